@@ -1,6 +1,7 @@
 //! JSON parser implementation.
 use somen::{call, prelude::*};
 use somen_language::numeric::{float::float, signed};
+use somen_language::string::{string, Escapes};
 use somen_language::token;
 use std::collections::HashMap;
 
@@ -39,7 +40,7 @@ token! {
         @[match = number]
         Number(f64) = signed(float, false),
         @[match = string]
-        String(String) = string(),
+        String(String) = string(b"\"", Escapes::json()),
     }
 }
 
@@ -63,35 +64,6 @@ fn spaces<'a, I: Input<Ok = char> + 'a>() -> impl Parser<I, Output = ()> + 'a {
         .expect("spaces")
 }
 
-fn string<'a, I: Input<Ok = char> + ?Sized + 'a>() -> impl Parser<I, Output = String> + 'a {
-    choice((
-        none_of("\\\""),
-        token('\\').prefix(one_of("\"\\/bfnrtu")).then(|c| match c {
-            '\"' => value('\"').left(),
-            '\\' => value('\\').left(),
-            '/' => value('/').left(),
-            'b' => value('\x08').left(),
-            'f' => value('\x0c').left(),
-            'n' => value('\n').left(),
-            'r' => value('\r').left(),
-            't' => value('\t').left(),
-            'u' => one_of("0123456789abcdefABCDEF")
-                .expect("a hex digit")
-                .times(4)
-                .collect::<String>()
-                .try_map(|s| {
-                    char::from_u32(u32::from_str_radix(&s, 16).unwrap())
-                        .ok_or("a valid unicode codepoint")
-                })
-                .right(),
-            _ => unreachable!(),
-        }),
-    ))
-    .repeat(..)
-    .collect::<String>()
-    .between(token('"'), token('"'))
-}
-
 fn main() {
     futures_executor::block_on(async {
         let mut stream = stream::from_iter(
@@ -4,6 +4,11 @@ use alloc::string::ToString;
 use somen::error::Expects;
 use somen::prelude::*;
 
+#[cfg(feature = "unicode")]
+#[cfg_attr(feature = "nightly", doc(cfg(feature = "unicode")))]
+#[doc(no_inline)]
+pub use unicode_general_category::GeneralCategory;
+
 /// A parser for characters.
 ///
 /// # Panics
@@ -18,11 +23,70 @@ where
     is(move |c: &C| c.eq_byte(byte)).expect(unsafe { C::byte_to_expect_unchecked(byte) })
 }
 
+/// A parser for a sequence of ascii characters, like [`tag`] for `char`s.
+///
+/// # Panics
+/// if `tag` contains non-ascii characters.
+///
+/// [`tag`]: somen::parser::tag
+pub fn characters<'a, I, C>(tag: &'static str) -> impl Parser<I, Output = ()> + 'a
+where
+    I: Positioned<Ok = C> + ?Sized + 'a,
+    C: Character + 'a,
+{
+    characters_with(tag, false)
+}
+
+/// A parser for a sequence of ascii characters like [`characters`], ignoring ascii case
+/// differences.
+///
+/// # Panics
+/// if `tag` contains non-ascii characters.
+pub fn characters_ignore_case<'a, I, C>(tag: &'static str) -> impl Parser<I, Output = ()> + 'a
+where
+    I: Positioned<Ok = C> + ?Sized + 'a,
+    C: Character + 'a,
+{
+    characters_with(tag, true)
+}
+
+fn characters_with<'a, I, C>(
+    tag: &'static str,
+    case_insensitive: bool,
+) -> impl Parser<I, Output = ()> + 'a
+where
+    I: Positioned<Ok = C> + ?Sized + 'a,
+    C: Character + 'a,
+{
+    assert!(tag.is_ascii());
+    let bytes = tag.as_bytes();
+    any()
+        .times(bytes.len())
+        .enumerate()
+        .fold(value(true), move |eq, (i, c): (usize, C)| {
+            eq && if case_insensitive {
+                c.eq_byte_ignore_case(bytes[i])
+            } else {
+                c.eq_byte(bytes[i])
+            }
+        })
+        .try_map(move |eq| if eq { Ok(()) } else { Err(tag) })
+        .rewindable()
+        .spanned()
+        .expect(tag)
+}
+
 /// A trait for characters.
 pub trait Character: Clone {
     /// Checks if the character equals to an ascii `byte` (in range of `0x00..=0x7F`).
     fn eq_byte(&self, byte: u8) -> bool;
 
+    /// Checks if the character equals to an ascii `byte`, ignoring ascii case differences.
+    #[inline]
+    fn eq_byte_ignore_case(&self, byte: u8) -> bool {
+        self.eq_byte(byte.to_ascii_lowercase()) || self.eq_byte(byte.to_ascii_uppercase())
+    }
+
     /// An unsafe version of [`byte_to_expect`]
     ///
     /// # Safety
@@ -54,8 +118,100 @@ pub trait Character: Clone {
     }
 
     /// Checks if the character is a lower or upper case letter.
+    ///
+    /// Note that this method only accepts ascii letters, use [`is_alphabetic`] for unicode letters.
+    ///
+    /// [`is_alphabetic`]: Self::is_alphabetic
     fn is_letter(&self) -> bool;
 
+    /// Checks if the character has the `Alphabetic` property of Unicode.
+    ///
+    /// Characters which have no unicode informations (like `u8`) only accept ascii letters.
+    #[inline]
+    fn is_alphabetic(&self) -> bool {
+        self.is_letter()
+    }
+
+    /// Checks if the character is a lowercase letter.
+    ///
+    /// Characters which have no unicode informations (like `u8`) only accept ascii letters.
+    #[inline]
+    fn is_lowercase(&self) -> bool {
+        (b'a'..=b'z').any(|b| self.eq_byte(b))
+    }
+
+    /// Checks if the character is an uppercase letter.
+    ///
+    /// Characters which have no unicode informations (like `u8`) only accept ascii letters.
+    #[inline]
+    fn is_uppercase(&self) -> bool {
+        (b'A'..=b'Z').any(|b| self.eq_byte(b))
+    }
+
+    /// Checks if the character has one of the general categories `Nd`, `Nl` or `No` of Unicode.
+    ///
+    /// Characters which have no unicode informations (like `u8`) only accept ascii digits.
+    #[inline]
+    fn is_numeric(&self) -> bool {
+        self.is_digit(10)
+    }
+
+    /// Checks if the character is alphabetic or numeric.
+    #[inline]
+    fn is_alphanumeric(&self) -> bool {
+        self.is_alphabetic() || self.is_numeric()
+    }
+
+    /// Checks if the character has the `White_Space` property of Unicode.
+    ///
+    /// Characters which have no unicode informations (like `u8`) only accept ascii whitespaces.
+    #[inline]
+    fn is_whitespace(&self) -> bool {
+        b" \t\n\x0c\r".iter().any(|b| self.eq_byte(*b))
+    }
+
+    /// Checks if the character has the `XID_Start` property of Unicode.
+    ///
+    /// Characters which have no unicode informations (like `u8`) only accept ascii letters.
+    #[cfg(feature = "unicode")]
+    #[cfg_attr(feature = "nightly", doc(cfg(feature = "unicode")))]
+    #[inline]
+    fn is_xid_start(&self) -> bool {
+        self.is_letter()
+    }
+
+    /// Checks if the character has the `XID_Continue` property of Unicode.
+    ///
+    /// Characters which have no unicode informations (like `u8`) only accept ascii letters, digits
+    /// and underscores.
+    #[cfg(feature = "unicode")]
+    #[cfg_attr(feature = "nightly", doc(cfg(feature = "unicode")))]
+    #[inline]
+    fn is_xid_continue(&self) -> bool {
+        self.is_letter() || self.is_digit(10) || self.eq_byte(b'_')
+    }
+
+    /// Returns the general category of Unicode.
+    ///
+    /// Characters which have no unicode informations (like `u8`) returns the category of the
+    /// corresponding ascii character, or [`GeneralCategory::Unassigned`] for non-ascii ones.
+    #[cfg(feature = "unicode")]
+    #[cfg_attr(feature = "nightly", doc(cfg(feature = "unicode")))]
+    fn general_category(&self) -> GeneralCategory {
+        (0..0x80)
+            .find(|b| self.eq_byte(*b))
+            .map(|b| unicode_general_category::get_general_category(b as char))
+            .unwrap_or(GeneralCategory::Unassigned)
+    }
+
+    /// Checks if the general category of the character is one of `categories`.
+    #[cfg(feature = "unicode")]
+    #[cfg_attr(feature = "nightly", doc(cfg(feature = "unicode")))]
+    #[inline]
+    fn is_general_category(&self, categories: &[GeneralCategory]) -> bool {
+        categories.contains(&self.general_category())
+    }
+
     /// Checks if the character is a digit with the radix, or not.
     ///
     /// # Panics
@@ -104,6 +260,54 @@ impl Character for char {
         self.is_ascii_alphabetic()
     }
 
+    #[inline]
+    fn is_alphabetic(&self) -> bool {
+        Self::is_alphabetic(*self)
+    }
+
+    #[inline]
+    fn is_lowercase(&self) -> bool {
+        Self::is_lowercase(*self)
+    }
+
+    #[inline]
+    fn is_uppercase(&self) -> bool {
+        Self::is_uppercase(*self)
+    }
+
+    #[inline]
+    fn is_numeric(&self) -> bool {
+        Self::is_numeric(*self)
+    }
+
+    #[inline]
+    fn is_alphanumeric(&self) -> bool {
+        Self::is_alphanumeric(*self)
+    }
+
+    #[inline]
+    fn is_whitespace(&self) -> bool {
+        Self::is_whitespace(*self)
+    }
+
+    #[cfg(feature = "unicode")]
+    #[inline]
+    fn is_xid_start(&self) -> bool {
+        unicode_ident::is_xid_start(*self)
+    }
+
+    #[cfg(feature = "unicode")]
+    #[inline]
+    fn is_xid_continue(&self) -> bool {
+        unicode_ident::is_xid_continue(*self)
+    }
+
+    #[cfg(feature = "unicode")]
+    #[inline]
+    fn general_category(&self) -> GeneralCategory {
+        unicode_general_category::get_general_category(*self)
+    }
+
     #[inline]
     fn is_digit(&self, radix: u8) -> bool {
         Self::is_digit(*self, radix as u32)
@@ -126,6 +330,58 @@ impl Character for u8 {
         self.is_ascii_alphabetic()
     }
 
+    #[inline]
+    fn is_alphabetic(&self) -> bool {
+        self.is_ascii_alphabetic()
+    }
+
+    #[inline]
+    fn is_lowercase(&self) -> bool {
+        self.is_ascii_lowercase()
+    }
+
+    #[inline]
+    fn is_uppercase(&self) -> bool {
+        self.is_ascii_uppercase()
+    }
+
+    #[inline]
+    fn is_numeric(&self) -> bool {
+        self.is_ascii_digit()
+    }
+
+    #[inline]
+    fn is_alphanumeric(&self) -> bool {
+        self.is_ascii_alphanumeric()
+    }
+
+    #[inline]
+    fn is_whitespace(&self) -> bool {
+        self.is_ascii_whitespace()
+    }
+
+    #[cfg(feature = "unicode")]
+    #[inline]
+    fn is_xid_start(&self) -> bool {
+        self.is_ascii_alphabetic()
+    }
+
+    #[cfg(feature = "unicode")]
+    #[inline]
+    fn is_xid_continue(&self) -> bool {
+        self.is_ascii_alphanumeric() || *self == b'_'
+    }
+
+    #[cfg(feature = "unicode")]
+    #[inline]
+    fn general_category(&self) -> GeneralCategory {
+        if self.is_ascii() {
+            unicode_general_category::get_general_category(*self as char)
+        } else {
+            GeneralCategory::Unassigned
+        }
+    }
+
     #[inline]
     fn is_digit(&self, radix: u8) -> bool {
         if self.is_ascii_digit() {
@@ -1,7 +1,88 @@
 //! Parsers for identifiers.
+#[cfg(feature = "alloc")]
+use alloc::{format, string::String};
+use somen::error::Expects;
 use somen::prelude::*;
 
-use crate::character::Character;
+use crate::character::{character, characters, Character};
+
+/// An identifier or a reserved keyword, the output of [`keyword_or_identifier`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Identifier<K, E> {
+    /// A reserved keyword.
+    Keyword(K),
+    /// A non-keyword identifier.
+    Ident(E),
+}
+
+/// Case styles of identifiers.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum CaseStyle {
+    /// Lowercase words separated by underscores, like `snake_case`.
+    Snake,
+    /// Uppercase words separated by underscores, like `SCREAMING_CASE`.
+    ScreamingSnake,
+    /// Lowercase words separated by hyphens, like `kebab-case`.
+    Kebab,
+    /// Capitalized words except the first word, like `camelCase`.
+    Camel,
+    /// Capitalized words, like `PascalCase`.
+    Pascal,
+}
+
+impl CaseStyle {
+    /// Checks if the sequence of characters follows the case style.
+    ///
+    /// Identifiers must start with a letter, and separators can not be doubled or trailing.
+    pub fn is_match<T, C>(self, chars: T) -> bool
+    where
+        T: IntoIterator<Item = C>,
+        C: Character,
+    {
+        let mut prev = None;
+        for c in chars {
+            if !self.accepts(prev.as_ref(), &c) {
+                return false;
+            }
+            prev = Some(c);
+        }
+        prev.is_some_and(|c| !self.is_separator(&c))
+    }
+
+    /// Returns the description of the case style, used as an [`Expects`].
+    pub fn description(self) -> &'static str {
+        match self {
+            Self::Snake => "a snake_case identifier",
+            Self::ScreamingSnake => "a SCREAMING_CASE identifier",
+            Self::Kebab => "a kebab-case identifier",
+            Self::Camel => "a camelCase identifier",
+            Self::Pascal => "a PascalCase identifier",
+        }
+    }
+
+    fn is_separator<C: Character>(self, c: &C) -> bool {
+        match self {
+            Self::Snake | Self::ScreamingSnake => c.eq_byte(b'_'),
+            Self::Kebab => c.eq_byte(b'-'),
+            Self::Camel | Self::Pascal => false,
+        }
+    }
+
+    fn accepts<C: Character>(self, prev: Option<&C>, c: &C) -> bool {
+        match prev {
+            None => match self {
+                Self::ScreamingSnake | Self::Pascal => c.is_uppercase(),
+                Self::Snake | Self::Kebab | Self::Camel => c.is_lowercase(),
+            },
+            Some(prev) if self.is_separator(c) => !self.is_separator(prev),
+            Some(_) => match self {
+                Self::Snake | Self::Kebab => c.is_lowercase() || c.is_numeric(),
+                Self::ScreamingSnake => c.is_uppercase() || c.is_numeric(),
+                Self::Camel | Self::Pascal => c.is_alphanumeric(),
+            },
+        }
+    }
+}
 
 /// Parses identifiers.
 #[inline]
@@ -30,3 +111,308 @@ where
         is(|c: &C| c.is_letter() || c.is_digit(10) || c.eq_byte(b'_')),
     )
 }
+
+/// Parses unicode identifiers defined in [UAX #31], starts with a `XID_Start` character and rest
+/// are `XID_Continue` characters.
+///
+/// [UAX #31]: https://www.unicode.org/reports/tr31/
+#[cfg(feature = "unicode")]
+#[cfg_attr(feature = "nightly", doc(cfg(feature = "unicode")))]
+#[inline]
+pub fn unicode_identifier<'a, E, I, C>() -> impl Parser<I, Output = E> + 'a
+where
+    E: Extend<C> + Default + 'a,
+    I: Input<Ok = C> + ?Sized + 'a,
+    C: Character + 'a,
+{
+    identifier(is(C::is_xid_start), is(C::is_xid_continue)).expect("an identifier")
+}
+
+/// Parses unicode identifiers like [`unicode_identifier`], with additional characters.
+///
+/// If `underscore` is `true`, identifiers can also start with underscores (like Rust or Python),
+/// and if `dollar` is `true`, dollar signs are allowed for any position (like JavaScript).
+#[cfg(feature = "unicode")]
+#[cfg_attr(feature = "nightly", doc(cfg(feature = "unicode")))]
+#[inline]
+pub fn unicode_identifier_with<'a, E, I, C>(
+    underscore: bool,
+    dollar: bool,
+) -> impl Parser<I, Output = E> + 'a
+where
+    E: Extend<C> + Default + 'a,
+    I: Input<Ok = C> + ?Sized + 'a,
+    C: Character + 'a,
+{
+    identifier(
+        is(move |c: &C| {
+            c.is_xid_start() || (underscore && c.eq_byte(b'_')) || (dollar && c.eq_byte(b'$'))
+        }),
+        is(move |c: &C| c.is_xid_continue() || (dollar && c.eq_byte(b'$'))),
+    )
+    .expect("an identifier")
+}
+
+/// Parses unicode identifiers like [`unicode_identifier_with`], and normalizes the output into
+/// the Normalization Form C (NFC).
+#[cfg(feature = "normalization")]
+#[cfg_attr(feature = "nightly", doc(cfg(feature = "normalization")))]
+#[inline]
+pub fn unicode_identifier_nfc<'a, E, I>(
+    underscore: bool,
+    dollar: bool,
+) -> impl Parser<I, Output = E> + 'a
+where
+    E: Extend<char> + Default + 'a,
+    I: Input<Ok = char> + ?Sized + 'a,
+{
+    use unicode_normalization::UnicodeNormalization;
+
+    unicode_identifier_with::<alloc::string::String, _, _>(underscore, dollar).map(|ident| {
+        let mut res = E::default();
+        res.extend(ident.nfc());
+        res
+    })
+}
+
+/// Takes an identifier parser, rejects reserved `keywords`.
+///
+/// Since `ident` parses a whole identifier before checking, identifiers start with a keyword
+/// (e.g. `iffy` for the keyword `if`) are not rejected.
+pub fn non_keyword<'a, P, K, E, I>(ident: P, keywords: &'a [K]) -> impl Parser<I, Output = E> + 'a
+where
+    P: Parser<I, Output = E> + 'a,
+    K: AsRef<[u8]>,
+    E: PartialEq<K> + 'a,
+    I: Positioned + ?Sized + 'a,
+{
+    ident.try_map(move |ident| match keywords.iter().find(|k| ident == **k) {
+        Some(keyword) => Err(keyword_expects(keyword)),
+        None => Ok(ident),
+    })
+}
+
+/// Takes an identifier parser, returns [`Identifier::Keyword`] if the identifier is one of
+/// `keywords`, or [`Identifier::Ident`] otherwise.
+///
+/// Like [`non_keyword`], identifiers start with a keyword are treated as [`Identifier::Ident`].
+pub fn keyword_or_identifier<'a, P, K, E, I>(
+    ident: P,
+    keywords: &'a [K],
+) -> impl Parser<I, Output = Identifier<K, E>> + 'a
+where
+    P: Parser<I, Output = E> + 'a,
+    K: Clone,
+    E: PartialEq<K> + 'a,
+    I: Positioned + ?Sized + 'a,
+{
+    ident.map(move |ident| match keywords.iter().find(|k| ident == **k) {
+        Some(keyword) => Identifier::Keyword(keyword.clone()),
+        None => Identifier::Ident(ident),
+    })
+}
+
+#[inline]
+#[cfg_attr(not(feature = "alloc"), allow(unused_variables))]
+fn keyword_expects<K: AsRef<[u8]>>(keyword: &K) -> Expects {
+    #[cfg(feature = "alloc")]
+    {
+        Expects::from(format!(
+            "an identifier, not keyword `{}`",
+            String::from_utf8_lossy(keyword.as_ref())
+        ))
+    }
+    #[cfg(not(feature = "alloc"))]
+    {
+        Expects::from("an identifier, not a keyword")
+    }
+}
+
+/// Parses raw identifiers prefixed by `prefix`, like `r#` for Rust or `@` for C#.
+///
+/// The output doesn't contain the prefix. Note that `prefix` must be ascii characters.
+#[inline]
+pub fn raw_identifier<'a, P, E, I, C>(
+    prefix: &'static str,
+    ident: P,
+) -> impl Parser<I, Output = E> + 'a
+where
+    P: Parser<I, Output = E> + 'a,
+    I: Positioned<Ok = C> + ?Sized + 'a,
+    C: Character + 'a,
+{
+    characters(prefix).prefix(ident)
+}
+
+/// Parses identifiers quoted by `open` and `close`, like backticks for Kotlin or Scala, or double
+/// quotes for SQL.
+///
+/// Quoted identifiers can contain any characters except `close`, and if `doubled` is `true`, two
+/// successive `close`s are unescaped to one `close` (e.g. `"a""b"` for `a"b` in SQL). Empty
+/// identifiers are not allowed.
+///
+/// # Panics
+/// if `open` or `close` is not an ascii character.
+pub fn quoted_identifier<'a, E, I, C>(
+    open: u8,
+    close: u8,
+    doubled: bool,
+) -> impl Parser<I, Output = E> + 'a
+where
+    E: Extend<C> + Default + 'a,
+    I: Input<Ok = C> + ?Sized + 'a,
+    C: Character + 'a,
+{
+    let content = is_not(move |c: &C| c.eq_byte(close));
+    let content = if doubled {
+        content
+            .or(character(close).skip(character(close)).spanned())
+            .left()
+    } else {
+        content.right()
+    };
+
+    content
+        .expect("a character")
+        .repeat(1..)
+        .collect()
+        .between(character(open), character(close))
+        .expect("a quoted identifier")
+}
+
+/// Takes a raw identifier parser and a normal identifier parser, returns the identifier and
+/// `true` if it was raw.
+///
+/// Raw identifiers are tried first, so `raw` must fail without consuming input if the input is
+/// not a raw identifier, as [`raw_identifier`] and [`quoted_identifier`] do.
+#[inline]
+pub fn raw_or_identifier<'a, P, Q, E, I>(
+    raw: P,
+    ident: Q,
+) -> impl Parser<I, Output = (E, bool)> + 'a
+where
+    P: Parser<I, Output = E> + 'a,
+    Q: Parser<I, Output = E> + 'a,
+    I: Input + ?Sized + 'a,
+{
+    raw.map(|ident| (ident, true))
+        .or(ident.map(|ident| (ident, false)))
+}
+
+/// Parses identifiers consist of letters, digits, underscores (and hyphens for
+/// [`CaseStyle::Kebab`]), and checks if the identifier follows the case `style`.
+pub fn cased_identifier<'a, E, I, C>(style: CaseStyle) -> impl Parser<I, Output = E> + 'a
+where
+    E: Extend<C> + Default + 'a,
+    I: Input<Ok = C> + ?Sized + 'a,
+    C: Character + 'a,
+{
+    (
+        is(C::is_alphabetic).once(),
+        is(move |c: &C| {
+            c.is_alphanumeric() || c.eq_byte(b'_') || (style == CaseStyle::Kebab && c.eq_byte(b'-'))
+        })
+        .repeat(..),
+    )
+        .fold(
+            value_fn(|| (E::default(), None, true)),
+            move |(mut ident, prev, matched): (E, Option<C>, bool), c| {
+                let matched = matched && style.accepts(prev.as_ref(), &c);
+                ident.extend(core::iter::once(c.clone()));
+                (ident, Some(c), matched)
+            },
+        )
+        .try_map(move |(ident, last, matched)| {
+            if matched && last.is_some_and(|c| !style.is_separator(&c)) {
+                Ok(ident)
+            } else {
+                Err(style.description())
+            }
+        })
+        .expect(style.description())
+}
+
+/// Parses identifiers like [`identifier`], returns the identifier normalized by `fold` together
+/// with the original spelling.
+///
+/// `fold` converts each character into zero or more characters, like [`char::to_lowercase`] or
+/// `|c: u8| [c.to_ascii_lowercase()]`.
+pub fn case_folded_identifier<'a, P, Q, F, T, E, I, C>(
+    start: P,
+    rest: Q,
+    fold: F,
+) -> impl Parser<I, Output = (E, E)> + 'a
+where
+    P: Parser<I, Output = C> + 'a,
+    Q: Parser<I, Output = C> + 'a,
+    F: Fn(C) -> T + 'a,
+    T: IntoIterator<Item = C>,
+    E: Extend<C> + Default + 'a,
+    I: Input<Ok = C> + ?Sized + 'a,
+    C: Character + 'a,
+{
+    (start.once(), rest.repeat(..)).fold(
+        value_fn(|| (E::default(), E::default())),
+        move |(mut folded, mut original): (E, E), c: C| {
+            folded.extend(fold(c.clone()));
+            original.extend(core::iter::once(c));
+            (folded, original)
+        },
+    )
+}
+
+/// Parses qualified paths, segments parsed by `segment` separated by `separator`.
+///
+/// Trailing separators are not allowed. The output can be any [`Extend`] of segments, like
+/// `Vec`.
+#[inline]
+pub fn qualified_path<'a, P, S, E, F, I>(
+    segment: P,
+    separator: S,
+) -> impl Parser<I, Output = F> + 'a
+where
+    P: Parser<I, Output = E> + 'a,
+    S: Parser<I> + 'a,
+    F: Extend<E> + Default + 'a,
+    I: Input + ?Sized + 'a,
+{
+    segment.sep_by(separator, 1..).collect()
+}
+
+/// Parses qualified paths of [`standard_identifier`]s separated by `separator`, like `::` for
+/// `a::b::C` or `.` for `pkg.mod.name`.
+///
+/// Note that `separator` must be ascii characters.
+#[inline]
+pub fn qualified_identifier<'a, E, F, I, C>(
+    separator: &'static str,
+) -> impl Parser<I, Output = F> + 'a
+where
+    E: Extend<C> + Default + 'a,
+    F: Extend<E> + Default + 'a,
+    I: Input<Ok = C> + ?Sized + 'a,
+    C: Character + 'a,
+{
+    qualified_path(standard_identifier(), characters(separator))
+}
+
+/// Parses kebab-case paths like `foo-bar-baz`, segments of letters and digits separated by
+/// hyphens.
+///
+/// Hyphens are only allowed between letters or digits.
+#[inline]
+pub fn kebab_path<'a, E, F, I, C>() -> impl Parser<I, Output = F> + 'a
+where
+    E: Extend<C> + Default + 'a,
+    F: Extend<E> + Default + 'a,
+    I: Input<Ok = C> + ?Sized + 'a,
+    C: Character + 'a,
+{
+    qualified_path(
+        is(C::is_alphanumeric)
+            .expect("a letter or a digit")
+            .repeat(1..)
+            .collect(),
+        character(b'-'),
+    )
+}
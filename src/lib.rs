@@ -11,3 +11,4 @@ pub mod character;
 pub mod identifier;
 mod macros;
 pub mod numeric;
+pub mod string;
@@ -1,10 +1,78 @@
 //! Parsers for numeric literals.
+use core::ops::Range;
+use num_traits::{
+    Bounded, CheckedAdd, CheckedMul, CheckedNeg, WrappingAdd, WrappingMul, WrappingNeg, Zero,
+};
 use somen::prelude::*;
 
 use crate::character::{character, Character};
 
+pub mod complex;
 pub mod float;
+pub mod formats;
 pub mod integer;
+pub mod rational;
+pub mod text;
+
+/// Policies for digit separators, like `_` in `1_000_000`.
+///
+/// Note that separators are never allowed before the first digit.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum SeparatorPolicy {
+    /// Only a single separator between two digits is allowed, like Python or JavaScript.
+    Between,
+    /// Successive separators between two digits are allowed, like Java.
+    BetweenRepeated,
+    /// Separators are allowed anywhere after the first digit, including doubled and trailing ones
+    /// like Rust.
+    Anywhere,
+}
+
+/// Policies for numbers which overflow their types.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum OverflowPolicy {
+    /// Rejects overflowed numbers.
+    Error,
+    /// Clamps overflowed numbers to the maximum or the minimum value of the type.
+    Saturate,
+    /// Wraps overflowed numbers around the boundary of the type, like two's complement integers.
+    Wrap,
+    /// Converts overflowed integers into floating point numbers, only supported by parsers which
+    /// return either of them like [`float::number`]. Other parsers treat it as [`Error`].
+    ///
+    /// [`Error`]: Self::Error
+    Float,
+}
+
+/// Integer types which can be parsed following [`OverflowPolicy`], implemented for all types with
+/// the checked and the wrapping arithmetic operations and the bounds.
+pub trait OverflowingInt:
+    Zero
+    + CheckedMul
+    + CheckedAdd
+    + CheckedNeg
+    + WrappingMul
+    + WrappingAdd
+    + WrappingNeg
+    + Bounded
+    + TryFrom<u8>
+    + Clone
+{
+}
+
+impl<N> OverflowingInt for N where
+    N: Zero
+        + CheckedMul
+        + CheckedAdd
+        + CheckedNeg
+        + WrappingMul
+        + WrappingAdd
+        + WrappingNeg
+        + Bounded
+        + TryFrom<u8>
+        + Clone
+{
+}
 
 /// Takes a function returns a integer parser, returns a parser of signed integer.
 ///
@@ -94,6 +162,193 @@ where
     digit(radix).repeat(1..)
 }
 
+/// Parses digits with given radix like [`digits`], separated by `separator` following `policy`.
+///
+/// Separators are not contained in the output.
+///
+/// # Panics
+/// if `separator` is not an ascii character.
+#[inline]
+pub fn digits_separated<'a, I, C>(
+    radix: u8,
+    separator: u8,
+    policy: SeparatorPolicy,
+) -> impl IterableParser<I, Item = C> + 'a
+where
+    I: Input<Ok = C> + ?Sized + 'a,
+    C: Character + 'a,
+{
+    (
+        non_zero_digit(radix).once(),
+        separated_rest(radix, separator, policy),
+    )
+        .or(character(b'0').once())
+}
+
+/// Parses digits with given radix like [`digits_trailing_zeros`], separated by `separator`
+/// following `policy`.
+///
+/// Separators are not contained in the output.
+///
+/// # Panics
+/// if `separator` is not an ascii character.
+#[inline]
+pub fn digits_trailing_zeros_separated<'a, I, C>(
+    radix: u8,
+    separator: u8,
+    policy: SeparatorPolicy,
+) -> impl IterableParser<I, Item = C> + 'a
+where
+    I: Input<Ok = C> + ?Sized + 'a,
+    C: Character + 'a,
+{
+    (
+        digit(radix).once(),
+        separated_rest(radix, separator, policy),
+    )
+}
+
+fn digits_with<'a, I, C>(
+    radix: u8,
+    separator: Option<(u8, SeparatorPolicy)>,
+) -> impl IterableParser<I, Item = C> + 'a
+where
+    I: Input<Ok = C> + ?Sized + 'a,
+    C: Character + 'a,
+{
+    match separator {
+        Some((separator, policy)) => digits_separated(radix, separator, policy).left(),
+        None => digits(radix).right(),
+    }
+}
+
+fn digits_trailing_zeros_with<'a, I, C>(
+    radix: u8,
+    separator: Option<(u8, SeparatorPolicy)>,
+) -> impl IterableParser<I, Item = C> + 'a
+where
+    I: Input<Ok = C> + ?Sized + 'a,
+    C: Character + 'a,
+{
+    match separator {
+        Some((separator, policy)) => {
+            digits_trailing_zeros_separated(radix, separator, policy).left()
+        }
+        None => digits_trailing_zeros(radix).right(),
+    }
+}
+
+fn separated_rest<'a, I, C>(
+    radix: u8,
+    separator: u8,
+    policy: SeparatorPolicy,
+) -> impl IterableParser<I, Item = C> + 'a
+where
+    I: Input<Ok = C> + ?Sized + 'a,
+    C: Character + 'a,
+{
+    match policy {
+        SeparatorPolicy::Between => character(separator)
+            .opt()
+            .prefix(digit(radix))
+            .repeat(..)
+            .left(),
+        SeparatorPolicy::BetweenRepeated => character(separator)
+            .repeat(..)
+            .discard()
+            .prefix(digit(radix))
+            .repeat(..)
+            .left()
+            .right(),
+        SeparatorPolicy::Anywhere => digit(radix)
+            .or(character(separator))
+            .repeat(..)
+            .filter(move |c: &C| !c.eq_byte(separator))
+            .right()
+            .right(),
+    }
+}
+
+/// Parses the longest run of characters satisfying `cond`, and returns its length and the value
+/// paired with the word in `words` equal to the run.
+///
+/// # Panics
+/// if `words` has more than 64 elements.
+fn longest_word<'a, T, F, I, C>(
+    words: &'a [(&'static str, T)],
+    cond: F,
+    case_insensitive: bool,
+) -> impl Parser<I, Output = (usize, Option<T>)> + 'a
+where
+    T: Copy + 'a,
+    F: FnMut(&C) -> bool + 'a,
+    I: Input<Ok = C> + ?Sized + 'a,
+    C: Character + 'a,
+{
+    assert!(words.len() <= 64);
+    let candidates = u64::MAX.checked_shr(64 - words.len() as u32).unwrap_or(0);
+
+    is(cond)
+        .repeat(..)
+        .fold(
+            value((0, candidates)),
+            move |(len, mut candidates): (usize, u64), c: C| {
+                for (i, (word, _)) in words.iter().enumerate() {
+                    let matched = word.as_bytes().get(len).is_some_and(|b| {
+                        if case_insensitive {
+                            c.eq_byte_ignore_case(*b)
+                        } else {
+                            c.eq_byte(*b)
+                        }
+                    });
+                    if !matched {
+                        candidates &= !(1 << i);
+                    }
+                }
+                (len + 1, candidates)
+            },
+        )
+        .map(move |(len, candidates)| {
+            let found = words
+                .iter()
+                .enumerate()
+                .find(|(i, (word, _))| candidates & (1 << i) != 0 && word.len() == len)
+                .map(|(_, (_, value))| *value);
+            (len, found)
+        })
+}
+
+/// Pushes a `digit` to `acc` following `policy`, returns the result and `true` if it has
+/// overflowed.
+fn push_digit<N>(acc: N, digit: u8, radix: u8, neg: bool, policy: OverflowPolicy) -> (N, bool)
+where
+    N: OverflowingInt,
+{
+    let operands = N::try_from(radix).ok().zip(N::try_from(digit).ok());
+    let res = operands.clone().and_then(|(radix, digit)| {
+        acc.checked_mul(&radix)
+            .zip(if neg {
+                digit.checked_neg()
+            } else {
+                Some(digit)
+            })
+            .and_then(|(acc, digit)| acc.checked_add(&digit))
+    });
+    match (res, policy) {
+        (Some(res), _) => (res, false),
+        (None, OverflowPolicy::Saturate) if neg => (N::min_value(), true),
+        (None, OverflowPolicy::Saturate) => (N::max_value(), true),
+        (None, OverflowPolicy::Wrap) => match operands {
+            Some((radix, digit)) => {
+                let digit = if neg { digit.wrapping_neg() } else { digit };
+                (acc.wrapping_mul(&radix).wrapping_add(&digit), true)
+            }
+            None => (acc, true),
+        },
+        (None, _) => (acc, true),
+    }
+}
+
 /// Parses fixed-length digits with given radix.
 #[inline]
 pub fn digits_fixed<'a, I, C>(length: usize, radix: u8) -> impl IterableParser<I, Item = C> + 'a
@@ -119,3 +374,59 @@ where
         minus.right()
     }
 }
+
+/// A numeric literal with its original spelling, returned by [`numeric_literal`].
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct NumericLiteral<N, E, L> {
+    /// The parsed value.
+    pub value: N,
+    /// The radix specified by a prefix, or `10` for unprefixed numbers.
+    pub radix: u8,
+    /// The source text of the literal, including signs, separators and suffixes.
+    pub raw_text: E,
+    /// The position of the literal.
+    pub span: Range<L>,
+}
+
+/// Takes a numeric parser returns the value and the radix, like
+/// [`integer::prefixed_integer_with_radix`], returns a parser of [`NumericLiteral`] which keeps
+/// the source text and the position of the literal.
+///
+/// The parser runs only once, and the source text is read again from the rewound input. Parsers
+/// without prefixes can be adapted by mapping the output, like `float(neg).map(|n| (n, 10))`.
+pub fn numeric_literal<'a, N, E, P, I, C>(
+    parser: P,
+) -> impl Parser<I, Output = NumericLiteral<N, E, I::Locator>> + 'a
+where
+    N: Clone + 'a,
+    E: Extend<C> + Default + 'a,
+    P: Parser<I, Output = (N, u8)> + 'a,
+    I: Input<Ok = C> + ?Sized + 'a,
+    I::Locator: PartialEq + Clone + 'a,
+    C: Character + 'a,
+{
+    parser
+        .with_position()
+        .peek()
+        .then(|((value, radix), span): ((N, u8), Range<I::Locator>)| {
+            let end = span.end.clone();
+            position()
+                .try_map(move |pos: I::Locator| {
+                    if pos == end {
+                        Err("the end of the literal")
+                    } else {
+                        Ok(())
+                    }
+                })
+                .rewindable()
+                .prefix(any())
+                .repeat(..)
+                .collect()
+                .map(move |raw_text| NumericLiteral {
+                    value: value.clone(),
+                    radix,
+                    raw_text,
+                    span: span.clone(),
+                })
+        })
+}
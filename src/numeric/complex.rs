@@ -0,0 +1,72 @@
+//! Parsers for imaginary and complex numbers.
+#[cfg(feature = "complex")]
+use num_traits::Zero;
+use somen::prelude::*;
+
+use crate::character::{characters, characters_ignore_case, Character};
+
+#[cfg(feature = "complex")]
+#[cfg_attr(feature = "nightly", doc(cfg(feature = "complex")))]
+#[doc(no_inline)]
+pub use num_complex::Complex;
+
+/// An imaginary number like `2j` or `3im`, a real number parsed by `real` followed by `suffix`.
+///
+/// The suffix is compared ignoring ascii case differences if `case_insensitive` is `true`, like
+/// `j` and `J` in Python.
+///
+/// # Panics
+/// if `suffix` contains non-ascii characters.
+#[inline]
+pub fn imaginary<'a, N, P, I, C>(
+    real: P,
+    suffix: &'static str,
+    case_insensitive: bool,
+) -> impl Parser<I, Output = N> + 'a
+where
+    P: Parser<I, Output = N> + 'a,
+    I: Input<Ok = C> + ?Sized + 'a,
+    C: Character + 'a,
+{
+    real.skip(imaginary_suffix(suffix, case_insensitive))
+}
+
+/// A real or imaginary number as a complex number, a real number parsed by `real` followed by an
+/// optional `suffix` like [`imaginary`].
+///
+/// # Panics
+/// if `suffix` contains non-ascii characters.
+#[cfg(feature = "complex")]
+#[cfg_attr(feature = "nightly", doc(cfg(feature = "complex")))]
+pub fn complex<'a, N, P, I, C>(
+    real: P,
+    suffix: &'static str,
+    case_insensitive: bool,
+) -> impl Parser<I, Output = Complex<N>> + 'a
+where
+    N: Zero + 'a,
+    P: Parser<I, Output = N> + 'a,
+    I: Input<Ok = C> + ?Sized + 'a,
+    C: Character + 'a,
+{
+    real.and(imaginary_suffix(suffix, case_insensitive).opt())
+        .map(|(x, im)| match im {
+            Some(()) => Complex::new(N::zero(), x),
+            None => Complex::new(x, N::zero()),
+        })
+}
+
+fn imaginary_suffix<'a, I, C>(
+    suffix: &'static str,
+    case_insensitive: bool,
+) -> impl Parser<I, Output = ()> + 'a
+where
+    I: Input<Ok = C> + ?Sized + 'a,
+    C: Character + 'a,
+{
+    if case_insensitive {
+        characters_ignore_case(suffix).left()
+    } else {
+        characters(suffix).right()
+    }
+}
@@ -1,9 +1,12 @@
 //! Parsers for floating point decimals.
 use somen::prelude::*;
 
-use super::integer::fold_digits;
-use super::{digits, digits_trailing_zeros, signed};
-use crate::character::{character, Character};
+use super::integer::fold_digits_overflowing;
+use super::{
+    digit, digits_trailing_zeros_with, digits_with, longest_word, push_digit, signed,
+    OverflowPolicy, OverflowingInt, SeparatorPolicy,
+};
+use crate::character::{character, characters, Character};
 
 #[doc(no_inline)]
 pub use compute_float::compute_float;
@@ -11,10 +14,10 @@ pub use compute_float::compute_float;
 /// A floating point number.
 ///
 /// This parser requires an integer part, but a decimal part and an exponent are optional.
-/// If you want to apply different rules, you can implement it by yourself using [`compute_float()`]
-/// helper function.
+/// If you want to apply different rules, use [`FloatFormat`] instead.
 ///
-/// Also note that this function doesn't support infinities and NaNs.
+/// Also note that this function doesn't support infinities and NaNs, use [`float_with_specials`]
+/// for them.
 #[cfg(any(feature = "std", feature = "libm"))]
 #[inline]
 pub fn float<'a, N, I, C>(neg: bool) -> impl Parser<I, Output = N> + 'a
@@ -23,70 +26,1016 @@ where
     I: Input<Ok = C> + ?Sized + 'a,
     C: Character + 'a,
 {
-    float_inner().map(move |(man, exp10)| {
-        compute_float(neg, man, exp10).unwrap_or_else(|| {
-            let res = N::from(man).unwrap() * N::from(10u8).unwrap().powi(exp10);
-            if neg {
-                -res
+    FloatFormat::new().float(neg)
+}
+
+#[inline]
+#[cfg(not(any(feature = "std", feature = "libm")))]
+pub fn float<'a, N, I, C>(neg: bool) -> impl Parser<I, Output = N> + 'a
+where
+    N: compute_float::Float + 'a,
+    I: Input<Ok = C> + ?Sized + 'a,
+    C: Character + 'a,
+{
+    FloatFormat::new().float(neg)
+}
+
+/// A floating point number like [`float`], each digits are separated by `separator` following
+/// `policy`.
+///
+/// # Panics
+/// if `separator` is not an ascii character.
+#[cfg(any(feature = "std", feature = "libm"))]
+#[inline]
+pub fn float_separated<'a, N, I, C>(
+    neg: bool,
+    separator: u8,
+    policy: SeparatorPolicy,
+) -> impl Parser<I, Output = N> + 'a
+where
+    N: compute_float::Float + num_traits::Float + 'a,
+    I: Input<Ok = C> + ?Sized + 'a,
+    C: Character + 'a,
+{
+    FloatFormat::new().separator(separator, policy).float(neg)
+}
+
+#[inline]
+#[cfg(not(any(feature = "std", feature = "libm")))]
+pub fn float_separated<'a, N, I, C>(
+    neg: bool,
+    separator: u8,
+    policy: SeparatorPolicy,
+) -> impl Parser<I, Output = N> + 'a
+where
+    N: compute_float::Float + 'a,
+    I: Input<Ok = C> + ?Sized + 'a,
+    C: Character + 'a,
+{
+    FloatFormat::new().separator(separator, policy).float(neg)
+}
+
+/// A builder of floating point number grammars.
+///
+/// By the default, it accepts the same grammar as [`float`]: an integer part without leading
+/// zeros is required, and a decimal part and an exponent (starts with `e` or `E`) are optional.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct FloatFormat {
+    leading_dot: bool,
+    trailing_dot: bool,
+    leading_zeros: bool,
+    reject_integers: bool,
+    exponent_chars: &'static [u8],
+    exponent_digits_required: bool,
+    exponent_plus_sign: bool,
+    exponent_overflow: OverflowPolicy,
+    separator: Option<(u8, SeparatorPolicy)>,
+    separator_after_exponent: bool,
+}
+
+impl Default for FloatFormat {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl FloatFormat {
+    /// Creates a default format.
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            leading_dot: false,
+            trailing_dot: false,
+            leading_zeros: false,
+            reject_integers: false,
+            exponent_chars: b"eE",
+            exponent_digits_required: true,
+            exponent_plus_sign: true,
+            exponent_overflow: OverflowPolicy::Saturate,
+            separator: None,
+            separator_after_exponent: false,
+        }
+    }
+
+    /// Allows numbers without integer parts like `.5`. (default: `false`)
+    #[inline]
+    pub const fn leading_dot(mut self, allowed: bool) -> Self {
+        self.leading_dot = allowed;
+        self
+    }
+
+    /// Allows decimal points without decimal parts like `5.`. (default: `false`)
+    #[inline]
+    pub const fn trailing_dot(mut self, allowed: bool) -> Self {
+        self.trailing_dot = allowed;
+        self
+    }
+
+    /// Allows leading zeros in integer parts like `01.5`. (default: `false`)
+    #[inline]
+    pub const fn leading_zeros(mut self, allowed: bool) -> Self {
+        self.leading_zeros = allowed;
+        self
+    }
+
+    /// Requires a decimal part or an exponent, so integers like `1` are rejected. (default:
+    /// `false`)
+    #[inline]
+    pub const fn reject_integers(mut self, rejected: bool) -> Self {
+        self.reject_integers = rejected;
+        self
+    }
+
+    /// Sets ascii characters starting exponents, exponents are disallowed if it is empty.
+    /// (default: `b"eE"`)
+    #[inline]
+    pub const fn exponent_chars(mut self, chars: &'static [u8]) -> Self {
+        self.exponent_chars = chars;
+        self
+    }
+
+    /// Requires digits after exponent characters, otherwise `1e` is accepted as `1e0`. (default:
+    /// `true`)
+    #[inline]
+    pub const fn exponent_digits_required(mut self, required: bool) -> Self {
+        self.exponent_digits_required = required;
+        self
+    }
+
+    /// Allows plus signs in exponents like `1e+5`. (default: `true`)
+    #[inline]
+    pub const fn exponent_plus_sign(mut self, allowed: bool) -> Self {
+        self.exponent_plus_sign = allowed;
+        self
+    }
+
+    /// Handles exponents which don't fit in `i32` following `policy`. (default:
+    /// [`OverflowPolicy::Saturate`], which makes too large numbers infinities and too small ones
+    /// zeros)
+    ///
+    /// [`OverflowPolicy::Float`] is treated as [`OverflowPolicy::Error`].
+    #[inline]
+    pub const fn exponent_overflow(mut self, policy: OverflowPolicy) -> Self {
+        self.exponent_overflow = policy;
+        self
+    }
+
+    /// Separates digits by `separator` following `policy`. (default: no separators)
+    ///
+    /// Note that the produced parser will panic if `separator` is not an ascii character.
+    #[inline]
+    pub const fn separator(mut self, separator: u8, policy: SeparatorPolicy) -> Self {
+        self.separator = Some((separator, policy));
+        self
+    }
+
+    /// Allows separators just after exponent characters and signs like `1e_5` in Rust, has no
+    /// effects if no separators are set. (default: `false`)
+    #[inline]
+    pub const fn separator_after_exponent(mut self, allowed: bool) -> Self {
+        self.separator_after_exponent = allowed;
+        self
+    }
+
+    /// Produces a parser of floating point numbers, negative if `neg` is `true`.
+    #[cfg(any(feature = "std", feature = "libm"))]
+    pub fn float<'a, N, I, C>(self, neg: bool) -> impl Parser<I, Output = N> + 'a
+    where
+        N: compute_float::Float + num_traits::Float + 'a,
+        I: Input<Ok = C> + ?Sized + 'a,
+        C: Character + 'a,
+    {
+        self.inner()
+            .map(move |(man, exp10)| to_float(neg, man, exp10))
+    }
+
+    /// Produces a parser of floating point numbers, negative if `neg` is `true`.
+    #[cfg(not(any(feature = "std", feature = "libm")))]
+    pub fn float<'a, N, I, C>(self, neg: bool) -> impl Parser<I, Output = N> + 'a
+    where
+        N: compute_float::Float + 'a,
+        I: Input<Ok = C> + ?Sized + 'a,
+        C: Character + 'a,
+    {
+        self.inner().try_map(move |(man, exp10)| {
+            compute_float(neg, man, exp10).ok_or("a valid floating point number")
+        })
+    }
+
+    /// Produces a parser of integers or floating point numbers like [`number`], negative if `neg`
+    /// is `true`.
+    ///
+    /// If `reject_integers` is `true`, integers are only produced by overflowing into floating
+    /// point numbers with [`OverflowPolicy::Float`].
+    #[cfg(any(feature = "std", feature = "libm"))]
+    pub fn number<'a, N, F, I, C>(
+        self,
+        neg: bool,
+        overflow: OverflowPolicy,
+    ) -> impl Parser<I, Output = Number<N, F>> + 'a
+    where
+        N: OverflowingInt + 'a,
+        F: compute_float::Float + num_traits::Float + 'a,
+        I: Input<Ok = C> + ?Sized + 'a,
+        C: Character + 'a,
+    {
+        self.number_parts(neg, overflow).try_map(
+            move |((man, int, int_overflowed), (exp, _), is_float)| {
+                if is_float || (int_overflowed && overflow == OverflowPolicy::Float) {
+                    Ok(Number::Float(to_float(neg, man.man, man.exp10(exp))))
+                } else if int_overflowed && overflow == OverflowPolicy::Error {
+                    Err("a not too large number.")
+                } else {
+                    Ok(Number::Int(int))
+                }
+            },
+        )
+    }
+
+    /// Produces a parser of integers or floating point numbers like [`number`], negative if `neg`
+    /// is `true`.
+    ///
+    /// If `reject_integers` is `true`, integers are only produced by overflowing into floating
+    /// point numbers with [`OverflowPolicy::Float`].
+    #[cfg(not(any(feature = "std", feature = "libm")))]
+    pub fn number<'a, N, F, I, C>(
+        self,
+        neg: bool,
+        overflow: OverflowPolicy,
+    ) -> impl Parser<I, Output = Number<N, F>> + 'a
+    where
+        N: OverflowingInt + 'a,
+        F: compute_float::Float + 'a,
+        I: Input<Ok = C> + ?Sized + 'a,
+        C: Character + 'a,
+    {
+        self.number_parts(neg, overflow).try_map(
+            move |((man, int, int_overflowed), (exp, _), is_float)| {
+                if is_float || (int_overflowed && overflow == OverflowPolicy::Float) {
+                    compute_float(neg, man.man, man.exp10(exp))
+                        .map(Number::Float)
+                        .ok_or("a valid floating point number")
+                } else if int_overflowed && overflow == OverflowPolicy::Error {
+                    Err("a not too large number.")
+                } else {
+                    Ok(Number::Int(int))
+                }
+            },
+        )
+    }
+
+    /// Produces a parser of exact decimal numbers like [`decimal`], negative if `neg` is `true`.
+    pub fn decimal<'a, I, C>(self, neg: bool) -> impl Parser<I, Output = Decimal> + 'a
+    where
+        I: Input<Ok = C> + ?Sized + 'a,
+        C: Character + 'a,
+    {
+        self.parts(
+            (0i128, 0i32, false),
+            move |(man, exp, overflowed): (i128, i32, bool), c: C, fraction| {
+                let digit = c.to_digit(10).unwrap() as i128;
+                let res = man.checked_mul(10).and_then(|man| {
+                    if neg {
+                        man.checked_sub(digit)
+                    } else {
+                        man.checked_add(digit)
+                    }
+                });
+                match res {
+                    Some(man) if !overflowed => (man, exp.saturating_sub(fraction as i32), false),
+                    _ => (man, exp, true),
+                }
+            },
+        )
+        .try_map(
+            |((mantissa, count, man_overflowed), (exp, exp_overflowed), _)| {
+                if man_overflowed {
+                    return Err("a decimal number within 128-bit precision");
+                }
+                match exp.checked_add(count) {
+                    Some(exponent) if !exp_overflowed => Ok(Decimal { mantissa, exponent }),
+                    _ => Err("a decimal number with a not too large exponent"),
+                }
+            },
+        )
+        .expect("a decimal number")
+    }
+
+    fn inner<'a, I, C>(self) -> impl Parser<I, Output = (u64, i32)> + 'a
+    where
+        I: Input<Ok = C> + ?Sized + 'a,
+        C: Character + 'a,
+    {
+        self.parts(DecMantissa::default(), |man, c, fraction| {
+            man.push(c, fraction)
+        })
+        .map(|(man, (exp, _), _)| (man.man, man.exp10(exp)))
+    }
+
+    fn number_parts<'a, N, I, C>(
+        self,
+        neg: bool,
+        overflow: OverflowPolicy,
+    ) -> impl Parser<I, Output = ((DecMantissa, N, bool), (i32, bool), bool)> + 'a
+    where
+        N: OverflowingInt + 'a,
+        I: Input<Ok = C> + ?Sized + 'a,
+        C: Character + 'a,
+    {
+        self.parts(
+            (DecMantissa::default(), N::zero(), false),
+            move |(man, int, overflowed): (DecMantissa, N, bool), c: C, fraction| {
+                let digit = c.to_digit(10).unwrap();
+                let man = man.push(c, fraction);
+                let stops = matches!(overflow, OverflowPolicy::Error | OverflowPolicy::Float);
+                if fraction || (overflowed && stops) {
+                    return (man, int, overflowed);
+                }
+                let (int, res) = push_digit(int, digit, 10, neg, overflow);
+                (man, int, overflowed || res)
+            },
+        )
+    }
+
+    /// Parses an integer part, a decimal part and an exponent.
+    ///
+    /// Digits of the mantissa are folded into `acc` by `push`, which takes `true` for digits in
+    /// the decimal part. Returns the folded mantissa, an exponent with a flag whether it has
+    /// overflowed, and whether the number has a decimal part or an exponent.
+    fn parts<'a, A, P, I, C>(
+        self,
+        acc: A,
+        push: P,
+    ) -> impl Parser<I, Output = (A, (i32, bool), bool)> + 'a
+    where
+        A: Clone + 'a,
+        P: Fn(A, C, bool) -> A + Copy + 'a,
+        I: Input<Ok = C> + ?Sized + 'a,
+        C: Character + 'a,
+    {
+        let separator = self.separator;
+
+        let int_digits = if self.leading_zeros {
+            digits_trailing_zeros_with(10, separator).left()
+        } else {
+            digits_with(10, separator).right()
+        };
+        let init = acc.clone();
+        let int = int_digits
+            .fold(value_fn(move || init.clone()), move |acc, c| {
+                push(acc, c, false)
+            })
+            .map(|acc| (acc, true));
+        let int = if self.leading_dot {
+            int.or(value_fn(move || (acc.clone(), false))).left()
+        } else {
+            int.right()
+        };
+
+        let mantissa = int.then(move |(acc, has_int)| {
+            let init = acc.clone();
+            let fraction = digits_trailing_zeros_with(10, separator)
+                .fold(value_fn(move || init.clone()), move |acc, c| {
+                    push(acc, c, true)
+                });
+            let fraction = if self.trailing_dot && has_int {
+                let acc = acc.clone();
+                fraction.or(value_fn(move || acc.clone())).left()
+            } else {
+                fraction.right()
+            };
+            let fraction = character(b'.').prefix(fraction).map(|acc| (acc, true));
+            if has_int {
+                fraction.or(value_fn(move || (acc.clone(), false))).left()
             } else {
-                res
+                fraction.right()
             }
-        })
+        });
+
+        let exponent_chars = self.exponent_chars;
+        let exponent_overflow = self.exponent_overflow;
+        let after_exponent = match separator {
+            Some((separator, _)) if self.separator_after_exponent => Some(separator),
+            _ => None,
+        };
+        let exp_digits = move |neg| {
+            is(move |c: &C| after_exponent.is_some_and(|separator| c.eq_byte(separator)))
+                .repeat(..)
+                .discard()
+                .prefix(exponent_digits(separator, neg, exponent_overflow))
+        };
+        let exp = if self.exponent_digits_required {
+            signed(exp_digits, self.exponent_plus_sign).left()
+        } else {
+            signed(
+                move |neg| exp_digits(neg).or(value((0, 0, false))),
+                self.exponent_plus_sign,
+            )
+            .right()
+        };
+        let exp = is(move |c: &C| exponent_chars.iter().any(|b| c.eq_byte(*b)))
+            .expect("an exponent")
+            .prefix(exp)
+            .map(|(exp, _, overflowed)| (exp, overflowed, true))
+            .or(value((0, false, false)));
+
+        let reject_integers = self.reject_integers;
+        mantissa
+            .and(exp)
+            .try_map(move |((man, has_dot), (exp, exp_overflowed, has_exp))| {
+                if reject_integers && !has_dot && !has_exp {
+                    Err("a decimal point or an exponent")
+                } else {
+                    Ok((man, (exp, exp_overflowed), has_dot || has_exp))
+                }
+            })
+    }
+}
+
+/// Parses digits of an exponent, handling overflows following `policy`.
+fn exponent_digits<'a, I, C>(
+    separator: Option<(u8, SeparatorPolicy)>,
+    neg: bool,
+    policy: OverflowPolicy,
+) -> impl Parser<I, Output = (i32, usize, bool)> + 'a
+where
+    I: Input<Ok = C> + ?Sized + 'a,
+    C: Character + 'a,
+{
+    fold_digits_overflowing(
+        digits_trailing_zeros_with(10, separator),
+        0i32,
+        10,
+        neg,
+        policy,
+    )
+    .try_map(move |res: (i32, usize, bool)| match policy {
+        OverflowPolicy::Error | OverflowPolicy::Float if res.2 => Err("a not too large exponent"),
+        _ => Ok(res),
     })
 }
 
+/// A decimal mantissa, represents `man * 10^exp`.
+///
+/// Digits which don't fit in `u64` are truncated, and once a digit is truncated, all the
+/// following digits are also truncated.
+#[derive(Clone, Copy, Debug, Default)]
+struct DecMantissa {
+    man: u64,
+    exp: i32,
+    truncated: bool,
+}
+
+impl DecMantissa {
+    fn push<C: Character>(mut self, c: C, fraction: bool) -> Self {
+        let digit = c.to_digit(10).unwrap() as u64;
+        let pushed = if self.truncated {
+            None
+        } else {
+            self.man
+                .checked_mul(10)
+                .and_then(|man| man.checked_add(digit))
+        };
+        match pushed {
+            Some(man) => {
+                self.man = man;
+                if fraction {
+                    self.exp = self.exp.saturating_sub(1);
+                }
+            }
+            None => {
+                self.truncated = true;
+                if !fraction {
+                    self.exp = self.exp.saturating_add(1);
+                }
+            }
+        }
+        self
+    }
+
+    /// Adds an exponent `exp`.
+    fn exp10(self, exp: i32) -> i32 {
+        exp.saturating_add(self.exp)
+    }
+}
+
+/// Numbers parsed by [`number`], an integer or a floating point number.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Number<N, F> {
+    /// An integer, which has neither a decimal part nor an exponent.
+    Int(N),
+    /// A floating point number, or an integer which overflowed `N`.
+    Float(F),
+}
+
+/// An integer or a floating point number like [`float`], parsed at once.
+///
+/// Numbers without decimal parts and exponents are integers. Integers which don't fit in `N` are
+/// handled following `overflow`, [`OverflowPolicy::Float`] converts them into floating point
+/// numbers instead of errors.
+#[cfg(any(feature = "std", feature = "libm"))]
+#[inline]
+pub fn number<'a, N, F, I, C>(
+    neg: bool,
+    overflow: OverflowPolicy,
+) -> impl Parser<I, Output = Number<N, F>> + 'a
+where
+    N: OverflowingInt + 'a,
+    F: compute_float::Float + num_traits::Float + 'a,
+    I: Input<Ok = C> + ?Sized + 'a,
+    C: Character + 'a,
+{
+    FloatFormat::new().number(neg, overflow)
+}
+
 #[inline]
 #[cfg(not(any(feature = "std", feature = "libm")))]
-pub fn float<'a, N, I, C>(neg: bool) -> impl Parser<I, Output = N> + 'a
+pub fn number<'a, N, F, I, C>(
+    neg: bool,
+    overflow: OverflowPolicy,
+) -> impl Parser<I, Output = Number<N, F>> + 'a
+where
+    N: OverflowingInt + 'a,
+    F: compute_float::Float + 'a,
+    I: Input<Ok = C> + ?Sized + 'a,
+    C: Character + 'a,
+{
+    FloatFormat::new().number(neg, overflow)
+}
+
+/// An exact decimal number, represents `mantissa * 10^exponent`.
+///
+/// The sign is carried by the mantissa, so `-1.5` is `Decimal { mantissa: -15, exponent: -1 }`.
+/// Note that trailing zeros are kept, so `1.0` and `1.00` are different representations of the
+/// same number, use [`normalize`] to compare them by value.
+///
+/// [`normalize`]: Self::normalize
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Decimal {
+    /// The signed digits of the number, negative for negative numbers.
+    pub mantissa: i128,
+    /// The power of ten multiplied to the mantissa, negative for fractional digits.
+    pub exponent: i32,
+}
+
+impl Decimal {
+    /// Removes trailing zeros from the mantissa, making the representation unique.
+    pub fn normalize(self) -> Self {
+        if self.mantissa == 0 {
+            return Self::default();
+        }
+        let Self {
+            mut mantissa,
+            mut exponent,
+        } = self;
+        while mantissa % 10 == 0 && exponent < i32::MAX {
+            mantissa /= 10;
+            exponent += 1;
+        }
+        Self { mantissa, exponent }
+    }
+}
+
+/// An exact decimal number like [`float`], without rounding into binary floating point numbers.
+///
+/// Numbers not representable by [`Decimal`] (mantissas which overflow `i128` or too large
+/// exponents) are rejected instead of losing precision.
+#[inline]
+pub fn decimal<'a, I, C>(neg: bool) -> impl Parser<I, Output = Decimal> + 'a
+where
+    I: Input<Ok = C> + ?Sized + 'a,
+    C: Character + 'a,
+{
+    FloatFormat::new().decimal(neg)
+}
+
+/// An exact decimal number like [`decimal`], each digits are separated by `separator` following
+/// `policy`.
+///
+/// # Panics
+/// if `separator` is not an ascii character.
+#[inline]
+pub fn decimal_separated<'a, I, C>(
+    neg: bool,
+    separator: u8,
+    policy: SeparatorPolicy,
+) -> impl Parser<I, Output = Decimal> + 'a
+where
+    I: Input<Ok = C> + ?Sized + 'a,
+    C: Character + 'a,
+{
+    FloatFormat::new().separator(separator, policy).decimal(neg)
+}
+
+/// Special floating point values, used in [`special_float`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum SpecialValue {
+    /// An infinity, like `inf` or `Infinity`.
+    Infinity,
+    /// A quiet NaN, like `nan` or `NaN`.
+    NaN,
+}
+
+impl SpecialValue {
+    /// Produces the floating point value, negative if `neg` is `true`.
+    ///
+    /// NaNs are quiet NaNs with empty payloads.
+    pub fn to_float<N: compute_float::Float>(self, neg: bool) -> N {
+        let exp = (1 << N::EXP_BITS) - 1;
+        match self {
+            Self::Infinity => N::from_fp(neg, 0, exp),
+            Self::NaN => N::from_fp(neg, 1 << (N::MANTISSA_BITS - 1), exp),
+        }
+    }
+}
+
+/// An infinity or a NaN spelled like one of `spellings`, such as `inf`, `.inf`, `Infinity` or
+/// `NaN`.
+///
+/// `spellings` is a list of pairs of ascii spellings and their values, compared ignoring ascii
+/// case differences if `case_insensitive` is `true`. Signs are not parsed, so use [`signed`]
+/// to accept `-inf`. The longest run of characters contained in
+/// `spellings` is parsed, and the input is rewound if it is not one of them or it is followed by
+/// an identifier character, like `info`.
+///
+/// # Panics
+/// if `spellings` has more than 64 elements.
+pub fn special_float<'a, N, I, C>(
+    spellings: &'a [(&'static str, SpecialValue)],
+    case_insensitive: bool,
+    neg: bool,
+) -> impl Parser<I, Output = N> + 'a
 where
     N: compute_float::Float + 'a,
     I: Input<Ok = C> + ?Sized + 'a,
     C: Character + 'a,
 {
-    float_inner().try_map(move |(man, exp10)| {
-        compute_float(neg, man, exp10).ok_or("a valid floating point number")
-    })
+    longest_word(
+        spellings,
+        move |c: &C| {
+            spellings.iter().any(|(spelling, _)| {
+                spelling.bytes().any(|b| {
+                    if case_insensitive {
+                        c.eq_byte_ignore_case(b)
+                    } else {
+                        c.eq_byte(b)
+                    }
+                })
+            })
+        },
+        case_insensitive,
+    )
+    .try_map(|(_, special)| special.ok_or("an infinity or a NaN"))
+    .skip(is(|c: &C| c.is_alphanumeric() || c.eq_byte(b'_')).fail())
+    .map(move |special| special.to_float(neg))
+    .rewindable()
+    .spanned()
+    .expect("an infinity or a NaN")
 }
 
-fn float_inner<'a, I, C>() -> impl Parser<I, Output = (u64, i32)> + 'a
+/// A floating point number like [`float`], or an infinity or a NaN like [`special_float`].
+///
+/// # Panics
+/// if `spellings` has more than 64 elements.
+#[cfg(any(feature = "std", feature = "libm"))]
+#[inline]
+pub fn float_with_specials<'a, N, I, C>(
+    spellings: &'a [(&'static str, SpecialValue)],
+    case_insensitive: bool,
+    neg: bool,
+) -> impl Parser<I, Output = N> + 'a
 where
+    N: compute_float::Float + num_traits::Float + 'a,
     I: Input<Ok = C> + ?Sized + 'a,
     C: Character + 'a,
 {
-    fold_digits::<u64, _, _, _>(digits(10), 0, 10, false)
-        .then(|(int, _, overflowed)| {
-            if overflowed {
-                value((int, 0, true)).left()
+    special_float(spellings, case_insensitive, neg).or(float(neg))
+}
+
+#[inline]
+#[cfg(not(any(feature = "std", feature = "libm")))]
+pub fn float_with_specials<'a, N, I, C>(
+    spellings: &'a [(&'static str, SpecialValue)],
+    case_insensitive: bool,
+    neg: bool,
+) -> impl Parser<I, Output = N> + 'a
+where
+    N: compute_float::Float + 'a,
+    I: Input<Ok = C> + ?Sized + 'a,
+    C: Character + 'a,
+{
+    special_float(spellings, case_insensitive, neg).or(float(neg))
+}
+
+/// A hexadecimal floating point number like `0x1.8p3`, consists of a `0x` prefix, a hexadecimal
+/// mantissa and a binary exponent.
+///
+/// Like C99 or C++17, either an integer part or a decimal part is required, and the exponent is
+/// always required. The conversion is exact and correctly rounded (to the nearest even).
+///
+/// Exponents which don't fit in `i32` are saturated, making too large numbers infinities and too
+/// small ones zeros.
+#[inline]
+pub fn hex_float<'a, N, I, C>(neg: bool) -> impl Parser<I, Output = N> + 'a
+where
+    N: compute_float::Float + 'a,
+    I: Input<Ok = C> + ?Sized + 'a,
+    C: Character + 'a,
+{
+    hex_float_overflowing(neg, OverflowPolicy::Saturate)
+}
+
+/// A hexadecimal floating point number like [`hex_float`], handling exponents which don't fit in
+/// `i32` following `policy` like [`FloatFormat::exponent_overflow`].
+pub fn hex_float_overflowing<'a, N, I, C>(
+    neg: bool,
+    policy: OverflowPolicy,
+) -> impl Parser<I, Output = N> + 'a
+where
+    N: compute_float::Float + 'a,
+    I: Input<Ok = C> + ?Sized + 'a,
+    C: Character + 'a,
+{
+    characters("0x")
+        .or(characters("0X"))
+        .prefix(
+            digit(16)
+                .repeat(..)
+                .fold(value(BinaryMantissa::default()), |man, c| {
+                    man.push(c, 16, false)
+                })
+                .then(|man| {
+                    character(b'.')
+                        .prefix(
+                            digit(16)
+                                .repeat(..)
+                                .fold(value(man), |man, c| man.push(c, 16, true)),
+                        )
+                        .or(value(man))
+                }),
+        )
+        .try_map(|man| {
+            if man.digits == 0 {
+                Err("a hex digit")
             } else {
-                character(b'.')
-                    .prefix(fold_digits(digits_trailing_zeros(10), int, 10, false))
-                    .or(value((int, 0, false)))
-                    .right()
+                Ok(man)
             }
         })
         .and(
-            character(b'e')
-                .or(character(b'E'))
-                .prefix(signed(
-                    |neg| fold_digits(digits_trailing_zeros(10), 0i32, 10, neg),
-                    true,
-                ))
-                .or(value((0, 0, false))),
-        )
-        .map(
-            |((mantissa, count, man_overflowed), (exp, _, exp_overflowed))| {
-                (
-                    if man_overflowed { u64::MAX } else { mantissa },
-                    if exp_overflowed {
-                        if exp < 0 {
-                            i32::MIN
-                        } else {
-                            i32::MAX
-                        }
-                    } else {
-                        exp.saturating_sub(count as i32)
-                    },
-                )
-            },
+            character(b'p')
+                .or(character(b'P'))
+                .prefix(signed(move |neg| exponent_digits(None, neg, policy), true)),
         )
+        .map(move |(man, (exp, _, _))| man.to_float(neg, exp))
+        .expect("a hexadecimal floating point number")
+}
+
+/// An integer with a power-of-two radix like `FF`, converted into a floating point number.
+///
+/// Integers of any length are accepted, and the conversion is exact and correctly rounded (to the
+/// nearest even) like hexadecimal integers of JavaScript.
+///
+/// # Panics
+/// if `radix` is not one of `2`, `4`, `8`, `16` or `32`.
+#[inline]
+pub fn integer_float<'a, N, I, C>(radix: u8, neg: bool) -> impl Parser<I, Output = N> + 'a
+where
+    N: compute_float::Float + 'a,
+    I: Input<Ok = C> + ?Sized + 'a,
+    C: Character + 'a,
+{
+    integer_float_inner(radix, neg, None)
+}
+
+/// An integer with a power-of-two radix converted into a floating point number like
+/// [`integer_float`], separated by `separator` following `policy`.
+///
+/// # Panics
+/// if `radix` is not one of `2`, `4`, `8`, `16` or `32`, or `separator` is not an ascii
+/// character.
+#[inline]
+pub fn integer_float_separated<'a, N, I, C>(
+    radix: u8,
+    neg: bool,
+    separator: u8,
+    policy: SeparatorPolicy,
+) -> impl Parser<I, Output = N> + 'a
+where
+    N: compute_float::Float + 'a,
+    I: Input<Ok = C> + ?Sized + 'a,
+    C: Character + 'a,
+{
+    integer_float_inner(radix, neg, Some((separator, policy)))
+}
+
+#[cfg_attr(not(feature = "alloc"), allow(unused_variables))]
+fn integer_float_inner<'a, N, I, C>(
+    radix: u8,
+    neg: bool,
+    separator: Option<(u8, SeparatorPolicy)>,
+) -> impl Parser<I, Output = N> + 'a
+where
+    N: compute_float::Float + 'a,
+    I: Input<Ok = C> + ?Sized + 'a,
+    C: Character + 'a,
+{
+    assert!(radix.is_power_of_two() && (2..=32).contains(&radix));
+    let integer = digits_trailing_zeros_with(radix, separator)
+        .fold(value(BinaryMantissa::default()), move |man, c| {
+            man.push(c, radix, false)
+        })
+        .map(move |man| man.to_float(neg, 0));
+
+    #[cfg(feature = "alloc")]
+    {
+        integer.expect(alloc::format!("an integer with radix {radix}"))
+    }
+    #[cfg(not(feature = "alloc"))]
+    {
+        integer.expect("an integer")
+    }
+}
+
+/// A mantissa of digits with a power-of-two radix, represents `man * 2^exp` (and more if `sticky`
+/// is `true`).
+#[derive(Clone, Copy, Debug, Default)]
+struct BinaryMantissa {
+    man: u64,
+    exp: i64,
+    sticky: bool,
+    digits: usize,
+}
+
+impl BinaryMantissa {
+    fn push<C: Character>(mut self, c: C, radix: u8, fraction: bool) -> Self {
+        let digit = c.to_digit(radix).unwrap() as u64;
+        let bits = radix.trailing_zeros();
+        self.digits += 1;
+        if self.man >> (64 - bits) == 0 {
+            self.man = self.man << bits | digit;
+            if fraction {
+                self.exp -= bits as i64;
+            }
+        } else {
+            self.sticky |= digit != 0;
+            if !fraction {
+                self.exp += bits as i64;
+            }
+        }
+        self
+    }
+
+    fn to_float<N: compute_float::Float>(self, neg: bool, exp2: i32) -> N {
+        let max_exp = (1 << N::EXP_BITS) - 1;
+        if self.man == 0 {
+            return N::from_fp(neg, 0, 0);
+        }
+
+        // Normalize the mantissa to `[2^63, 2^64)`, so the value is in `[2^exp, 2^(exp+1))`.
+        let zeros = self.man.leading_zeros();
+        let man = self.man << zeros;
+        let mut exp = self.exp + exp2 as i64 - zeros as i64 + 63;
+        if exp > N::EMAX as i64 {
+            return N::from_fp(neg, 0, max_exp);
+        }
+
+        // Subnormal numbers have less significant bits.
+        let min_exp = 1 - N::EMAX as i64;
+        let shift = (63 - N::MANTISSA_BITS as i64 + (min_exp - exp).max(0)) as u64;
+        let (mut kept, rest, half) = if shift < 64 {
+            (man >> shift, man & ((1 << shift) - 1), 1 << (shift - 1))
+        } else if shift == 64 {
+            (0, man, 1 << 63)
+        } else {
+            return N::from_fp(neg, 0, 0);
+        };
+
+        if rest > half || (rest == half && (self.sticky || kept & 1 == 1)) {
+            kept += 1;
+        }
+
+        if exp < min_exp {
+            // Rounding up may produce the minimum normal number, the carry goes to the exponent.
+            let exp2 = (kept >> N::MANTISSA_BITS) as u32;
+            N::from_fp(neg, kept & ((1 << N::MANTISSA_BITS) - 1), exp2)
+        } else {
+            if kept >> (N::MANTISSA_BITS + 1) != 0 {
+                kept >>= 1;
+                exp += 1;
+                if exp > N::EMAX as i64 {
+                    return N::from_fp(neg, 0, max_exp);
+                }
+            }
+            N::from_fp(
+                neg,
+                kept & ((1 << N::MANTISSA_BITS) - 1),
+                (exp + N::EMAX as i64) as u32,
+            )
+        }
+    }
+}
+
+#[cfg(any(feature = "std", feature = "libm"))]
+fn to_float<N>(neg: bool, man: u64, exp10: i32) -> N
+where
+    N: compute_float::Float + num_traits::Float,
+{
+    compute_float(neg, man, exp10).unwrap_or_else(|| {
+        let res = N::from(man).unwrap() * N::from(10u8).unwrap().powi(exp10);
+        if neg {
+            -res
+        } else {
+            res
+        }
+    })
+}
+
+#[cfg(all(test, feature = "alloc"))]
+mod tests {
+    use super::*;
+    use alloc::string::String;
+    use somen::stream;
+
+    macro_rules! parse {
+        ($parser:expr, $input:expr) => {
+            futures_executor::block_on(async {
+                let mut stream = stream::from_iter($input.chars())
+                    .positioned::<usize>()
+                    .buffered_rewind();
+                $parser.complete().parse(&mut stream).await.ok()
+            })
+        };
+    }
+
+    const SPECIALS: &[(&str, SpecialValue)] = &[
+        ("inf", SpecialValue::Infinity),
+        ("infinity", SpecialValue::Infinity),
+        ("nan", SpecialValue::NaN),
+    ];
+
+    #[test]
+    fn specials() {
+        for (input, expected) in [
+            ("inf", f64::INFINITY),
+            ("Infinity", f64::INFINITY),
+            ("INF", f64::INFINITY),
+        ] {
+            assert_eq!(
+                parse!(special_float::<f64, _, _>(SPECIALS, true, false), input),
+                Some(expected),
+                "{input}"
+            );
+        }
+        assert!(
+            parse!(special_float::<f64, _, _>(SPECIALS, true, false), "nan")
+                .unwrap()
+                .is_nan()
+        );
+        assert_eq!(
+            parse!(special_float::<f64, _, _>(SPECIALS, false, true), "inf"),
+            Some(f64::NEG_INFINITY)
+        );
+        for input in ["info", "nano", "inf_", "inf1", "infinit", "Inf"] {
+            assert_eq!(
+                parse!(special_float::<f64, _, _>(SPECIALS, false, false), input),
+                None,
+                "{input}"
+            );
+        }
+    }
+
+    #[test]
+    fn specials_word_boundary() {
+        let rest = || any().repeat(..).collect::<String>();
+        for (input, expected) in [
+            ("info", (false, "info")),
+            ("nano", (false, "nano")),
+            ("inf+1", (true, "+1")),
+            ("nan ", (true, " ")),
+        ] {
+            let special = special_float::<f64, _, _>(SPECIALS, false, false)
+                .map(|_| true)
+                .or(value(false));
+            assert_eq!(
+                parse!(special.and(rest()), input),
+                Some((expected.0, String::from(expected.1))),
+                "{input}"
+            );
+        }
+        assert_eq!(
+            parse!(
+                float_with_specials::<f64, _, _>(SPECIALS, false, false),
+                "1.5"
+            ),
+            Some(1.5)
+        );
+        assert_eq!(
+            parse!(
+                float_with_specials::<f64, _, _>(SPECIALS, false, false),
+                "info"
+            ),
+            None
+        );
+    }
 }
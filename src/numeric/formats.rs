@@ -0,0 +1,865 @@
+//! Ready-made number parsers following the specifications of common languages.
+//!
+//! Unless noted, parsers for languages whose signs are unary operators don't parse signs, and
+//! take an argument `neg` like other numeric parsers so they can be used with [`signed`].
+use num_traits::{CheckedAdd, CheckedMul, CheckedNeg, Zero};
+use somen::prelude::*;
+
+use super::float::{
+    float, hex_float, integer_float_separated, special_float, FloatFormat, SpecialValue,
+};
+use super::integer::{
+    integer_separated, prefixed_integer_separated, prefixed_integer_separated_with_radix,
+    with_int_suffix, IntLiteral, IntType, RadixPrefixes,
+};
+use super::{digits_trailing_zeros_separated, longest_word, sign, signed, SeparatorPolicy};
+use crate::character::{character, Character};
+
+const TOML_PREFIXES: RadixPrefixes = RadixPrefixes {
+    hex: true,
+    octal: true,
+    binary: true,
+    legacy_octal: false,
+    uppercase: false,
+    leading_zeros: false,
+    separator_after_prefix: false,
+};
+
+const TOML_FLOAT: FloatFormat = FloatFormat::new()
+    .reject_integers(true)
+    .separator(b'_', SeparatorPolicy::Between);
+
+const TOML_SPECIALS: &[(&str, SpecialValue)] =
+    &[("inf", SpecialValue::Infinity), ("nan", SpecialValue::NaN)];
+
+const RUST_PREFIXES: RadixPrefixes = RadixPrefixes {
+    hex: true,
+    octal: true,
+    binary: true,
+    legacy_octal: false,
+    uppercase: false,
+    leading_zeros: true,
+    separator_after_prefix: true,
+};
+
+const RUST_INT_SUFFIXES: &[(&str, IntType)] = &[
+    ("i8", IntType::I8),
+    ("i16", IntType::I16),
+    ("i32", IntType::I32),
+    ("i64", IntType::I64),
+    ("i128", IntType::I128),
+    ("isize", IntType::Isize),
+    ("u8", IntType::U8),
+    ("u16", IntType::U16),
+    ("u32", IntType::U32),
+    ("u64", IntType::U64),
+    ("u128", IntType::U128),
+    ("usize", IntType::Usize),
+];
+
+const RUST_FLOAT: FloatFormat = FloatFormat::new()
+    .leading_zeros(true)
+    .reject_integers(true)
+    .separator(b'_', SeparatorPolicy::Anywhere)
+    .separator_after_exponent(true);
+
+const RUST_FLOAT_SUFFIXES: &[(&str, ())] = &[("f32", ()), ("f64", ())];
+
+const C_PREFIXES: RadixPrefixes = RadixPrefixes {
+    hex: true,
+    octal: false,
+    binary: true,
+    legacy_octal: true,
+    uppercase: true,
+    leading_zeros: false,
+    separator_after_prefix: false,
+};
+
+const C_INT_SUFFIXES: &[(&str, ())] = &[
+    ("u", ()),
+    ("U", ()),
+    ("l", ()),
+    ("L", ()),
+    ("ul", ()),
+    ("uL", ()),
+    ("Ul", ()),
+    ("UL", ()),
+    ("lu", ()),
+    ("lU", ()),
+    ("Lu", ()),
+    ("LU", ()),
+    ("ll", ()),
+    ("LL", ()),
+    ("ull", ()),
+    ("uLL", ()),
+    ("Ull", ()),
+    ("ULL", ()),
+    ("llu", ()),
+    ("llU", ()),
+    ("LLu", ()),
+    ("LLU", ()),
+];
+
+const C_FLOAT: FloatFormat = FloatFormat::new()
+    .leading_dot(true)
+    .trailing_dot(true)
+    .leading_zeros(true)
+    .reject_integers(true)
+    .separator(b'\'', SeparatorPolicy::Between);
+
+const C_FLOAT_SUFFIXES: &[(&str, ())] = &[("f", ()), ("F", ()), ("l", ()), ("L", ())];
+
+const PYTHON_PREFIXES: RadixPrefixes = RadixPrefixes {
+    hex: true,
+    octal: true,
+    binary: true,
+    legacy_octal: false,
+    uppercase: true,
+    leading_zeros: false,
+    separator_after_prefix: true,
+};
+
+const PYTHON_FLOAT: FloatFormat = FloatFormat::new()
+    .leading_dot(true)
+    .trailing_dot(true)
+    .leading_zeros(true)
+    .reject_integers(true)
+    .separator(b'_', SeparatorPolicy::Between);
+
+const JAVASCRIPT_FLOAT: FloatFormat = FloatFormat::new()
+    .leading_dot(true)
+    .trailing_dot(true)
+    .separator(b'_', SeparatorPolicy::Between);
+
+const JAVASCRIPT_SPECIALS: &[(&str, SpecialValue)] = &[
+    ("Infinity", SpecialValue::Infinity),
+    ("NaN", SpecialValue::NaN),
+];
+
+/// A JSON number, including a minus sign.
+///
+/// Follows [RFC 8259](https://www.rfc-editor.org/rfc/rfc8259#section-6): leading zeros and plus
+/// signs are not allowed, and a decimal part and an exponent are optional.
+#[cfg(any(feature = "std", feature = "libm"))]
+#[inline]
+pub fn json_number<'a, N, I, C>() -> impl Parser<I, Output = N> + 'a
+where
+    N: compute_float::Float + num_traits::Float + 'a,
+    I: Input<Ok = C> + ?Sized + 'a,
+    C: Character + 'a,
+{
+    signed(float, false)
+}
+
+#[cfg(not(any(feature = "std", feature = "libm")))]
+#[inline]
+pub fn json_number<'a, N, I, C>() -> impl Parser<I, Output = N> + 'a
+where
+    N: compute_float::Float + 'a,
+    I: Input<Ok = C> + ?Sized + 'a,
+    C: Character + 'a,
+{
+    signed(float, false)
+}
+
+/// A TOML integer, including a sign.
+///
+/// Decimal integers may have signs and underscores between digits but no leading zeros, and
+/// `0x`, `0o` and `0b` prefixed integers are unsigned and may have leading zeros.
+pub fn toml_integer<'a, N, I, C>() -> impl Parser<I, Output = N> + 'a
+where
+    N: Zero + CheckedMul + CheckedAdd + CheckedNeg + TryFrom<u8> + Clone + 'a,
+    I: Input<Ok = C> + ?Sized + 'a,
+    C: Character + 'a,
+{
+    let signed = sign(true)
+        .then(|neg| integer_separated(10, neg, b'_', SeparatorPolicy::Between))
+        .skip(
+            is(|c: &C| c.eq_byte(b'x') || c.eq_byte(b'o') || c.eq_byte(b'b'))
+                .fail()
+                .expect("a decimal integer, prefixed integers can't have signs"),
+        )
+        .map(|n| (n, 10));
+    let unsigned =
+        prefixed_integer_separated_with_radix(TOML_PREFIXES, false, b'_', SeparatorPolicy::Between);
+    end_of_integer(
+        unsigned.or(signed),
+        b'_',
+        "a decimal integer without leading zeros",
+    )
+}
+
+/// A TOML float, including a sign.
+///
+/// A decimal part or an exponent is required, and `inf` and `nan` are accepted.
+#[cfg(any(feature = "std", feature = "libm"))]
+pub fn toml_float<'a, N, I, C>() -> impl Parser<I, Output = N> + 'a
+where
+    N: compute_float::Float + num_traits::Float + 'a,
+    I: Input<Ok = C> + ?Sized + 'a,
+    C: Character + 'a,
+{
+    signed(
+        |neg| special_float(TOML_SPECIALS, false, neg).or(TOML_FLOAT.float(neg)),
+        true,
+    )
+}
+
+#[cfg(not(any(feature = "std", feature = "libm")))]
+pub fn toml_float<'a, N, I, C>() -> impl Parser<I, Output = N> + 'a
+where
+    N: compute_float::Float + 'a,
+    I: Input<Ok = C> + ?Sized + 'a,
+    C: Character + 'a,
+{
+    signed(
+        |neg| special_float(TOML_SPECIALS, false, neg).or(TOML_FLOAT.float(neg)),
+        true,
+    )
+}
+
+/// A Rust integer literal with an optional type suffix like `0xFF_u8`.
+///
+/// Integers without suffixes are returned as [`IntLiteral::Wide`] without checking their ranges,
+/// because their types are inferred later. Underscores are allowed anywhere after the first digit
+/// or a prefix.
+///
+/// Decimal integers with float suffixes like `1f32` are float literals, so they are rejected
+/// without consuming the input, and can be parsed by [`rust_float`].
+pub fn rust_integer<'a, I, C>(neg: bool) -> impl Parser<I, Output = IntLiteral> + 'a
+where
+    I: Input<Ok = C> + ?Sized + 'a,
+    C: Character + 'a,
+{
+    rust_float_integer()
+        .fail()
+        .expect("an integer literal")
+        .prefix(with_int_suffix(
+            prefixed_integer_separated(RUST_PREFIXES, false, b'_', SeparatorPolicy::Anywhere),
+            neg,
+            RUST_INT_SUFFIXES,
+            IntType::Wide,
+        ))
+}
+
+/// A Rust float literal with an optional `f32` or `f64` suffix.
+///
+/// A decimal part, an exponent or a suffix is required, like `1.5`, `1e_5` or `1f32`. Floats end
+/// with decimal points like `2.` are accepted unless they are followed by `.`, `_` or an
+/// identifier character, like ranges `2..` or method calls `2.max(x)`.
+#[cfg(any(feature = "std", feature = "libm"))]
+pub fn rust_float<'a, N, I, C>(neg: bool) -> impl Parser<I, Output = N> + 'a
+where
+    N: compute_float::Float + num_traits::Float + 'a,
+    I: Input<Ok = C> + ?Sized + 'a,
+    C: Character + 'a,
+{
+    rust_float_integer()
+        .peek()
+        .prefix(RUST_FLOAT.reject_integers(false).float(neg))
+        .or(rust_trailing_dot()
+            .peek()
+            .prefix(RUST_FLOAT.trailing_dot(true).float(neg)))
+        .or(RUST_FLOAT.float(neg))
+        .skip(float_suffix(RUST_FLOAT_SUFFIXES))
+}
+
+#[cfg(not(any(feature = "std", feature = "libm")))]
+pub fn rust_float<'a, N, I, C>(neg: bool) -> impl Parser<I, Output = N> + 'a
+where
+    N: compute_float::Float + 'a,
+    I: Input<Ok = C> + ?Sized + 'a,
+    C: Character + 'a,
+{
+    rust_float_integer()
+        .peek()
+        .prefix(RUST_FLOAT.reject_integers(false).float(neg))
+        .or(rust_trailing_dot()
+            .peek()
+            .prefix(RUST_FLOAT.trailing_dot(true).float(neg)))
+        .or(RUST_FLOAT.float(neg))
+        .skip(float_suffix(RUST_FLOAT_SUFFIXES))
+}
+
+/// A C integer constant with an optional suffix like `0x1FUL`.
+///
+/// Octal integers starts with `0`, C23 binary integers and C23 digit separators `'` are accepted.
+/// Suffixes are validated but don't affect the output.
+pub fn c_integer<'a, N, I, C>(neg: bool) -> impl Parser<I, Output = N> + 'a
+where
+    N: Zero + CheckedMul + CheckedAdd + CheckedNeg + TryFrom<u8> + Clone + 'a,
+    I: Input<Ok = C> + ?Sized + 'a,
+    C: Character + 'a,
+{
+    end_of_integer(
+        prefixed_integer_separated_with_radix(C_PREFIXES, neg, b'\'', SeparatorPolicy::Between),
+        b'\'',
+        "an octal digit",
+    )
+    .skip(
+        longest_word(
+            C_INT_SUFFIXES,
+            |c: &C| c.is_alphanumeric() || c.eq_byte(b'_'),
+            false,
+        )
+        .try_map(|(len, suffix)| {
+            if len == 0 || suffix.is_some() {
+                Ok(())
+            } else {
+                Err("a valid integer suffix")
+            }
+        }),
+    )
+}
+
+/// A C floating constant with an optional suffix like `.5f`, including hexadecimal floats.
+///
+/// A decimal point or an exponent is required. Suffixes are validated but don't affect the
+/// output.
+#[cfg(any(feature = "std", feature = "libm"))]
+pub fn c_float<'a, N, I, C>(neg: bool) -> impl Parser<I, Output = N> + 'a
+where
+    N: compute_float::Float + num_traits::Float + 'a,
+    I: Input<Ok = C> + ?Sized + 'a,
+    C: Character + 'a,
+{
+    hex_float(neg)
+        .or(C_FLOAT.float(neg))
+        .skip(float_suffix(C_FLOAT_SUFFIXES))
+}
+
+#[cfg(not(any(feature = "std", feature = "libm")))]
+pub fn c_float<'a, N, I, C>(neg: bool) -> impl Parser<I, Output = N> + 'a
+where
+    N: compute_float::Float + 'a,
+    I: Input<Ok = C> + ?Sized + 'a,
+    C: Character + 'a,
+{
+    hex_float(neg)
+        .or(C_FLOAT.float(neg))
+        .skip(float_suffix(C_FLOAT_SUFFIXES))
+}
+
+/// A Python integer literal like `0x_FF` or `1_000`.
+///
+/// Leading zeros are only allowed in zeros like `00`.
+pub fn python_integer<'a, N, I, C>(neg: bool) -> impl Parser<I, Output = N> + 'a
+where
+    N: Zero + CheckedMul + CheckedAdd + CheckedNeg + TryFrom<u8> + Clone + 'a,
+    I: Input<Ok = C> + ?Sized + 'a,
+    C: Character + 'a,
+{
+    end_of_integer(
+        prefixed_integer_separated_with_radix(PYTHON_PREFIXES, neg, b'_', SeparatorPolicy::Between)
+            .skip(
+                character(b'_')
+                    .opt()
+                    .prefix(character(b'0'))
+                    .repeat(..)
+                    .discard(),
+            ),
+        b'_',
+        "a decimal integer without leading zeros",
+    )
+}
+
+/// A Python float literal like `.5`, `1.` or `1_000e-3`.
+///
+/// A decimal point or an exponent is required, and leading zeros are allowed.
+#[cfg(any(feature = "std", feature = "libm"))]
+#[inline]
+pub fn python_float<'a, N, I, C>(neg: bool) -> impl Parser<I, Output = N> + 'a
+where
+    N: compute_float::Float + num_traits::Float + 'a,
+    I: Input<Ok = C> + ?Sized + 'a,
+    C: Character + 'a,
+{
+    PYTHON_FLOAT.float(neg)
+}
+
+#[cfg(not(any(feature = "std", feature = "libm")))]
+#[inline]
+pub fn python_float<'a, N, I, C>(neg: bool) -> impl Parser<I, Output = N> + 'a
+where
+    N: compute_float::Float + 'a,
+    I: Input<Ok = C> + ?Sized + 'a,
+    C: Character + 'a,
+{
+    PYTHON_FLOAT.float(neg)
+}
+
+/// A JavaScript numeric literal, or `Infinity` and `NaN`.
+///
+/// Integers prefixed by `0x`, `0o` and `0b` are converted into floating point numbers like
+/// [`integer_float`], so integers of any length are accepted. Legacy octal integers and BigInt
+/// literals are not supported.
+///
+/// [`integer_float`]: super::float::integer_float
+#[cfg(any(feature = "std", feature = "libm"))]
+pub fn javascript_number<'a, N, I, C>(neg: bool) -> impl Parser<I, Output = N> + 'a
+where
+    N: compute_float::Float + num_traits::Float + 'a,
+    I: Input<Ok = C> + ?Sized + 'a,
+    C: Character + 'a,
+{
+    special_float(JAVASCRIPT_SPECIALS, false, neg)
+        .or(javascript_prefixed(b"xX", 16, neg))
+        .or(javascript_prefixed(b"oO", 8, neg))
+        .or(javascript_prefixed(b"bB", 2, neg))
+        .or(JAVASCRIPT_FLOAT.float(neg))
+}
+
+#[cfg(not(any(feature = "std", feature = "libm")))]
+pub fn javascript_number<'a, N, I, C>(neg: bool) -> impl Parser<I, Output = N> + 'a
+where
+    N: compute_float::Float + 'a,
+    I: Input<Ok = C> + ?Sized + 'a,
+    C: Character + 'a,
+{
+    special_float(JAVASCRIPT_SPECIALS, false, neg)
+        .or(javascript_prefixed(b"xX", 16, neg))
+        .or(javascript_prefixed(b"oO", 8, neg))
+        .or(javascript_prefixed(b"bB", 2, neg))
+        .or(JAVASCRIPT_FLOAT.float(neg))
+}
+
+/// Parses a Rust decimal integer followed by a float suffix, like `1f32`.
+fn rust_float_integer<'a, I, C>() -> impl Parser<I, Output = ()> + 'a
+where
+    I: Input<Ok = C> + ?Sized + 'a,
+    C: Character + 'a,
+{
+    digits_trailing_zeros_separated(10, b'_', SeparatorPolicy::Anywhere)
+        .discard()
+        .prefix(
+            longest_word(
+                RUST_FLOAT_SUFFIXES,
+                |c: &C| c.is_alphanumeric() || c.eq_byte(b'_'),
+                false,
+            )
+            .try_map(|(_, suffix)| suffix.ok_or("a float suffix")),
+        )
+        .rewindable()
+        .spanned()
+}
+
+/// Parses a Rust decimal integer followed by a decimal point without decimal digits, like `2.`.
+fn rust_trailing_dot<'a, I, C>() -> impl Parser<I, Output = ()> + 'a
+where
+    I: Input<Ok = C> + ?Sized + 'a,
+    C: Character + 'a,
+{
+    digits_trailing_zeros_separated(10, b'_', SeparatorPolicy::Anywhere)
+        .discard()
+        .skip(character(b'.'))
+        .skip(is(|c: &C| c.eq_byte(b'.') || c.eq_byte(b'_') || c.is_alphanumeric()).fail())
+        .rewindable()
+        .spanned()
+}
+
+fn javascript_prefixed<'a, N, I, C>(
+    prefixes: &'static [u8; 2],
+    radix: u8,
+    neg: bool,
+) -> impl Parser<I, Output = N> + 'a
+where
+    N: compute_float::Float + 'a,
+    I: Input<Ok = C> + ?Sized + 'a,
+    C: Character + 'a,
+{
+    character(b'0')
+        .prefix(character(prefixes[0]).or(character(prefixes[1])))
+        .rewindable()
+        .spanned()
+        .prefix(integer_float_separated(
+            radix,
+            neg,
+            b'_',
+            SeparatorPolicy::Between,
+        ))
+}
+
+/// Takes an integer parser returns the radix, and rejects digits or `separator` following the
+/// integer, like leading zeros `012` or digits out of the radix `0b12`.
+///
+/// `decimal` is the error message for digits following decimal integers.
+fn end_of_integer<'a, N, P, I, C>(
+    integer: P,
+    separator: u8,
+    decimal: &'static str,
+) -> impl Parser<I, Output = N> + 'a
+where
+    N: Clone + 'a,
+    P: Parser<I, Output = (N, u8)> + 'a,
+    I: Input<Ok = C> + ?Sized + 'a,
+    C: Character + 'a,
+{
+    integer.then(move |(n, radix)| {
+        is(move |c: &C| c.is_digit(10) || c.eq_byte(separator))
+            .fail()
+            .expect(match radix {
+                2 => "a binary digit",
+                8 => "an octal digit",
+                16 => "a hex digit",
+                _ => decimal,
+            })
+            .map(move |_| n.clone())
+    })
+}
+
+fn float_suffix<'a, I, C>(suffixes: &'a [(&'static str, ())]) -> impl Parser<I, Output = ()> + 'a
+where
+    I: Input<Ok = C> + ?Sized + 'a,
+    C: Character + 'a,
+{
+    longest_word(
+        suffixes,
+        |c: &C| c.is_alphanumeric() || c.eq_byte(b'_'),
+        false,
+    )
+    .try_map(|(len, suffix)| {
+        if len == 0 || suffix.is_some() {
+            Ok(())
+        } else {
+            Err("a valid float suffix")
+        }
+    })
+}
+
+#[cfg(test)]
+#[allow(clippy::approx_constant)]
+mod tests {
+    use super::*;
+    use somen::stream;
+
+    macro_rules! parse {
+        ($parser:expr, $input:expr) => {
+            futures_executor::block_on(async {
+                let mut stream = stream::from_iter($input.chars())
+                    .positioned::<usize>()
+                    .buffered_rewind();
+                $parser.complete().parse(&mut stream).await.ok()
+            })
+        };
+    }
+
+    #[test]
+    fn json() {
+        for (input, expected) in [
+            ("-0", -0.0),
+            ("0", 0.0),
+            ("1", 1.0),
+            ("-1.5", -1.5),
+            ("1e10", 1e10),
+            ("1E+2", 1e2),
+            ("1.0e-2", 1e-2),
+        ] {
+            assert_eq!(
+                parse!(json_number::<f64, _, _>(), input),
+                Some(expected),
+                "{input}"
+            );
+        }
+        for input in ["01", "+1", ".5", "1.", "-"] {
+            assert_eq!(parse!(json_number::<f64, _, _>(), input), None, "{input}");
+        }
+    }
+
+    #[test]
+    fn toml() {
+        for (input, expected) in [
+            ("+99", 99),
+            ("42", 42),
+            ("0", 0),
+            ("-17", -17),
+            ("1_000", 1000),
+            ("5_349_221", 5349221),
+            ("53_49_221", 5349221),
+            ("1_2_3_4_5", 12345),
+            ("0xDEADBEEF", 0xDEADBEEF),
+            ("0xdeadbeef", 0xDEADBEEF),
+            ("0xdead_beef", 0xDEADBEEF),
+            ("0o01234567", 0o1234567),
+            ("0o755", 0o755),
+            ("0b11010110", 0b11010110),
+            ("+0", 0),
+            ("-0", 0),
+        ] {
+            assert_eq!(
+                parse!(toml_integer::<i64, _, _>(), input),
+                Some(expected),
+                "{input}"
+            );
+        }
+        for input in ["012", "-0x1", "+0x1", "1__2", "1_", "0X1F"] {
+            assert_eq!(parse!(toml_integer::<i64, _, _>(), input), None, "{input}");
+        }
+
+        for (input, expected) in [
+            ("+1.0", 1.0),
+            ("3.1415", 3.1415),
+            ("-0.01", -0.01),
+            ("5e+22", 5e22),
+            ("1e06", 1e6),
+            ("-2E-2", -2e-2),
+            ("6.626e-34", 6.626e-34),
+            ("224_617.445_991_228", 224617.445991228),
+            ("inf", f64::INFINITY),
+            ("+inf", f64::INFINITY),
+            ("-inf", f64::NEG_INFINITY),
+        ] {
+            assert_eq!(
+                parse!(toml_float::<f64, _, _>(), input),
+                Some(expected),
+                "{input}"
+            );
+        }
+        assert!(parse!(toml_float::<f64, _, _>(), "nan").unwrap().is_nan());
+        for input in [".7", "7.", "3.e+20", "1"] {
+            assert_eq!(parse!(toml_float::<f64, _, _>(), input), None, "{input}");
+        }
+    }
+
+    #[test]
+    fn rust() {
+        for (input, expected) in [
+            (
+                "123",
+                IntLiteral::Wide {
+                    abs: 123,
+                    neg: false,
+                },
+            ),
+            ("123i32", IntLiteral::I32(123)),
+            ("123u32", IntLiteral::U32(123)),
+            ("123_u32", IntLiteral::U32(123)),
+            (
+                "0xff",
+                IntLiteral::Wide {
+                    abs: 0xff,
+                    neg: false,
+                },
+            ),
+            ("0xff_u8", IntLiteral::U8(0xff)),
+            (
+                "0x01_f32",
+                IntLiteral::Wide {
+                    abs: 0x01f32,
+                    neg: false,
+                },
+            ),
+            (
+                "0o70",
+                IntLiteral::Wide {
+                    abs: 0o70,
+                    neg: false,
+                },
+            ),
+            ("0o70_i16", IntLiteral::I16(0o70)),
+            (
+                "0b1111_1111_1001_0000",
+                IntLiteral::Wide {
+                    abs: 0b1111_1111_1001_0000,
+                    neg: false,
+                },
+            ),
+            (
+                "0b1111_1111_1001_0000i64",
+                IntLiteral::I64(0b1111_1111_1001_0000),
+            ),
+            ("0b________1", IntLiteral::Wide { abs: 1, neg: false }),
+            ("0usize", IntLiteral::Usize(0)),
+            (
+                "3000000000",
+                IntLiteral::Wide {
+                    abs: 3000000000,
+                    neg: false,
+                },
+            ),
+        ] {
+            assert_eq!(
+                parse!(rust_integer(false), input),
+                Some(expected),
+                "{input}"
+            );
+        }
+        for input in [
+            "0invalidSuffix",
+            "123AFB43",
+            "0b0102",
+            "0o0581",
+            "1f32",
+            "3000000000i32",
+        ] {
+            assert_eq!(parse!(rust_integer(false), input), None, "{input}");
+        }
+
+        for (input, expected) in [
+            ("123.0f64", 123.0),
+            ("0.1f64", 0.1),
+            ("0.1f32", 0.1),
+            ("12E+99_f64", 12e99),
+            ("5f32", 5.0),
+            ("1e_5", 1e5),
+            ("2.", 2.0),
+            ("1_000.", 1000.0),
+        ] {
+            assert_eq!(
+                parse!(rust_float::<f64, _, _>(false), input),
+                Some(expected),
+                "{input}"
+            );
+        }
+        for input in ["2.0e", "1", "2..", "2._", "2.max", "2.f32", "0x1f32"] {
+            assert_eq!(
+                parse!(rust_float::<f64, _, _>(false), input),
+                None,
+                "{input}"
+            );
+        }
+    }
+
+    #[test]
+    fn c() {
+        for (input, expected) in [
+            ("42", 42),
+            ("052", 42),
+            ("0x2a", 42),
+            ("0X2A", 42),
+            ("0b101010", 42),
+            ("42u", 42),
+            ("42UL", 42),
+            ("42ull", 42),
+            ("0x2aLLU", 42),
+            ("1'000'000", 1000000),
+            ("0", 0),
+        ] {
+            assert_eq!(
+                parse!(c_integer::<i64, _, _>(false), input),
+                Some(expected),
+                "{input}"
+            );
+        }
+        for input in ["08", "0x", "42lul"] {
+            assert_eq!(
+                parse!(c_integer::<i64, _, _>(false), input),
+                None,
+                "{input}"
+            );
+        }
+
+        for (input, expected) in [
+            ("1.0", 1.0),
+            ("1.", 1.0),
+            (".5", 0.5),
+            ("1e10", 1e10),
+            ("1.5f", 1.5),
+            ("0x1p-2", 0.25),
+            ("0x1.8p3L", 12.0),
+            ("1e-3L", 1e-3),
+            ("0x.8p1", 1.0),
+        ] {
+            assert_eq!(
+                parse!(c_float::<f64, _, _>(false), input),
+                Some(expected),
+                "{input}"
+            );
+        }
+        for input in ["1", "0x1.8"] {
+            assert_eq!(parse!(c_float::<f64, _, _>(false), input), None, "{input}");
+        }
+    }
+
+    #[test]
+    fn python() {
+        for (input, expected) in [
+            ("7", 7),
+            ("2147483647", 2147483647),
+            ("0o177", 0o177),
+            ("0b100110111", 0b100110111),
+            (
+                "79228162514264337593543950336",
+                79228162514264337593543950336,
+            ),
+            ("0o377", 0o377),
+            ("0xdeadbeef", 0xdeadbeef),
+            ("100_000_000_000", 100_000_000_000),
+            ("0b_1110_0101", 0b1110_0101),
+            ("0", 0),
+            ("00", 0),
+            ("0_0", 0),
+        ] {
+            assert_eq!(
+                parse!(python_integer::<u128, _, _>(false), input),
+                Some(expected),
+                "{input}"
+            );
+        }
+        for input in ["007", "0_7", "1__0", "1_", "0b12"] {
+            assert_eq!(
+                parse!(python_integer::<u128, _, _>(false), input),
+                None,
+                "{input}"
+            );
+        }
+
+        for (input, expected) in [
+            ("3.14", 3.14),
+            ("10.", 10.0),
+            (".001", 0.001),
+            ("1e100", 1e100),
+            ("3.14e-10", 3.14e-10),
+            ("0e0", 0.0),
+            ("3.14_15_93", 3.141593),
+            ("077e010", 77e10),
+        ] {
+            assert_eq!(
+                parse!(python_float::<f64, _, _>(false), input),
+                Some(expected),
+                "{input}"
+            );
+        }
+        for input in ["1", "1__0.5", "1_.5"] {
+            assert_eq!(
+                parse!(python_float::<f64, _, _>(false), input),
+                None,
+                "{input}"
+            );
+        }
+    }
+
+    #[test]
+    fn javascript() {
+        for (input, expected) in [
+            ("0", 0.0),
+            ("123", 123.0),
+            ("1.5", 1.5),
+            (".5", 0.5),
+            ("5.", 5.0),
+            ("1e3", 1e3),
+            ("1_000", 1e3),
+            ("0x1F", 31.0),
+            ("0XFF", 255.0),
+            ("0o17", 15.0),
+            ("0b101", 5.0),
+            ("0xFFFFFFFFFFFFFFFFFFFF", 1208925819614629174706175.0),
+            ("Infinity", f64::INFINITY),
+        ] {
+            assert_eq!(
+                parse!(javascript_number::<f64, _, _>(false), input),
+                Some(expected),
+                "{input}"
+            );
+        }
+        assert!(parse!(javascript_number::<f64, _, _>(false), "NaN")
+            .unwrap()
+            .is_nan());
+        for input in ["1__0", "1_", "0x", "0x_1"] {
+            assert_eq!(
+                parse!(javascript_number::<f64, _, _>(false), input),
+                None,
+                "{input}"
+            );
+        }
+    }
+}
@@ -1,9 +1,226 @@
 //! Parsers for integers.
+use core::ops::Bound::{Included, Unbounded};
+
 use num_traits::{CheckedAdd, CheckedMul, CheckedNeg, Zero};
 use somen::prelude::*;
 
-use super::{digits, digits_fixed, digits_trailing_zeros};
-use crate::character::Character;
+use super::{
+    digits, digits_fixed, digits_separated, digits_trailing_zeros, digits_trailing_zeros_separated,
+    digits_trailing_zeros_with, digits_with, longest_word, push_digit, OverflowPolicy,
+    OverflowingInt, SeparatorPolicy,
+};
+use crate::character::{character, Character};
+
+#[cfg(feature = "bigint")]
+#[cfg_attr(feature = "nightly", doc(cfg(feature = "bigint")))]
+#[doc(no_inline)]
+pub use num_bigint::BigInt;
+
+/// Integer types specified by suffixes, used in [`suffixed_integer`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum IntType {
+    /// `i8`
+    I8,
+    /// `i16`
+    I16,
+    /// `i32`
+    I32,
+    /// `i64`
+    I64,
+    /// `i128`
+    I128,
+    /// `isize`
+    Isize,
+    /// `u8`
+    U8,
+    /// `u16`
+    U16,
+    /// `u32`
+    U32,
+    /// `u64`
+    U64,
+    /// `u128`
+    U128,
+    /// `usize`
+    Usize,
+    /// An integer wider than the other types like JavaScript BigInt literals `5n`, limited to the
+    /// range of `u128` in absolute value.
+    ///
+    /// Larger values are rejected as errors, use `IntType::Big` with the `bigint` feature to accept
+    /// integers of any size.
+    Wide,
+    /// An arbitrary-precision integer like JavaScript BigInt literals `5n`.
+    #[cfg(feature = "bigint")]
+    #[cfg_attr(feature = "nightly", doc(cfg(feature = "bigint")))]
+    Big,
+}
+
+/// Typed integer literals, the output of [`suffixed_integer`].
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum IntLiteral {
+    /// An `i8` literal.
+    I8(i8),
+    /// An `i16` literal.
+    I16(i16),
+    /// An `i32` literal.
+    I32(i32),
+    /// An `i64` literal.
+    I64(i64),
+    /// An `i128` literal.
+    I128(i128),
+    /// An `isize` literal.
+    Isize(isize),
+    /// A `u8` literal.
+    U8(u8),
+    /// A `u16` literal.
+    U16(u16),
+    /// A `u32` literal.
+    U32(u32),
+    /// A `u64` literal.
+    U64(u64),
+    /// A `u128` literal.
+    U128(u128),
+    /// A `usize` literal.
+    Usize(usize),
+    /// A [`IntType::Wide`] literal, with the absolute value and the sign (`false` for zero).
+    Wide {
+        /// The absolute value.
+        abs: u128,
+        /// `true` if the value is negative.
+        neg: bool,
+    },
+    /// An arbitrary-precision integer literal.
+    #[cfg(feature = "bigint")]
+    #[cfg_attr(feature = "nightly", doc(cfg(feature = "bigint")))]
+    Big(BigInt),
+}
+
+impl IntType {
+    /// Converts an absolute value `abs` (negative if `neg` is `true`) into a literal of the type,
+    /// or returns [`None`] if the value is out of range.
+    pub fn convert(self, abs: u128, neg: bool) -> Option<IntLiteral> {
+        macro_rules! convert {
+            ($variant:ident, $ty:ty) => {
+                if neg {
+                    <$ty>::try_from(0i128.checked_sub_unsigned(abs)?).ok()
+                } else {
+                    <$ty>::try_from(abs).ok()
+                }
+                .map(IntLiteral::$variant)
+            };
+        }
+
+        match self {
+            Self::I8 => convert!(I8, i8),
+            Self::I16 => convert!(I16, i16),
+            Self::I32 => convert!(I32, i32),
+            Self::I64 => convert!(I64, i64),
+            Self::I128 => convert!(I128, i128),
+            Self::Isize => convert!(Isize, isize),
+            Self::U8 => convert!(U8, u8),
+            Self::U16 => convert!(U16, u16),
+            Self::U32 => convert!(U32, u32),
+            Self::U64 => convert!(U64, u64),
+            Self::U128 => convert!(U128, u128),
+            Self::Usize => convert!(Usize, usize),
+            Self::Wide => Some(IntLiteral::Wide {
+                abs,
+                neg: neg && abs != 0,
+            }),
+            #[cfg(feature = "bigint")]
+            Self::Big => {
+                let abs = BigInt::from(abs);
+                Some(IntLiteral::Big(if neg { -abs } else { abs }))
+            }
+        }
+    }
+
+    /// Converts an arbitrary-precision integer into a literal of the type, or returns [`None`] if
+    /// the value is out of range.
+    #[cfg(feature = "bigint")]
+    #[cfg_attr(feature = "nightly", doc(cfg(feature = "bigint")))]
+    pub fn convert_big(self, value: BigInt) -> Option<IntLiteral> {
+        match self {
+            Self::Big => Some(IntLiteral::Big(value)),
+            _ => {
+                let neg = value.sign() == num_bigint::Sign::Minus;
+                self.convert(u128::try_from(value.magnitude()).ok()?, neg)
+            }
+        }
+    }
+
+    fn range_expects(self) -> &'static str {
+        match self {
+            Self::I8 => "an integer in range for i8",
+            Self::I16 => "an integer in range for i16",
+            Self::I32 => "an integer in range for i32",
+            Self::I64 => "an integer in range for i64",
+            Self::I128 => "an integer in range for i128",
+            Self::Isize => "an integer in range for isize",
+            Self::U8 => "an integer in range for u8",
+            Self::U16 => "an integer in range for u16",
+            Self::U32 => "an integer in range for u32",
+            Self::U64 => "an integer in range for u64",
+            Self::U128 => "an integer in range for u128",
+            Self::Usize => "an integer in range for usize",
+            Self::Wide => "an integer in range for u128",
+            #[cfg(feature = "bigint")]
+            Self::Big => "an integer",
+        }
+    }
+}
+
+/// Radix prefixes accepted by [`prefixed_integer`].
+///
+/// By the default, `0x`, `0o` and `0b` are accepted.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct RadixPrefixes {
+    /// Accepts `0x` for hexadecimal integers.
+    pub hex: bool,
+    /// Accepts `0o` for octal integers.
+    pub octal: bool,
+    /// Accepts `0b` for binary integers.
+    pub binary: bool,
+    /// Accepts C-style octal integers starts with `0`, like `0755`.
+    pub legacy_octal: bool,
+    /// Also accepts upper case prefixes like `0X`.
+    pub uppercase: bool,
+    /// Accepts leading zeros in decimal integers like `007`, has no effects if `legacy_octal` is
+    /// `true`.
+    pub leading_zeros: bool,
+    /// Accepts separators just after prefixes like `0x_FF`, only used in separated integers.
+    pub separator_after_prefix: bool,
+}
+
+impl Default for RadixPrefixes {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            hex: true,
+            octal: true,
+            binary: true,
+            legacy_octal: false,
+            uppercase: false,
+            leading_zeros: false,
+            separator_after_prefix: false,
+        }
+    }
+}
+
+impl RadixPrefixes {
+    fn radix<C: Character>(&self, c: &C) -> Option<(u8, &'static str)> {
+        let eq = |lower: u8| c.eq_byte(lower) || (self.uppercase && c.eq_byte(lower - 0x20));
+        if self.hex && eq(b'x') {
+            Some((16, "a hex digit after `0x`"))
+        } else if self.octal && eq(b'o') {
+            Some((8, "an octal digit after `0o`"))
+        } else if self.binary && eq(b'b') {
+            Some((2, "a binary digit after `0b`"))
+        } else {
+            None
+        }
+    }
+}
 
 /// An integer with given radix which has no trailing zeros.
 #[inline]
@@ -13,23 +230,7 @@ where
     I: Input<Ok = C> + ?Sized + 'a,
     C: Character + 'a,
 {
-    let integer =
-        fold_digits(digits(radix), N::zero(), radix, neg).try_map(|(acc, _, overflowed)| {
-            if overflowed {
-                Err("a not too large number.")
-            } else {
-                Ok(acc)
-            }
-        });
-
-    #[cfg(feature = "alloc")]
-    {
-        integer.expect(alloc::format!("an integer with radix {radix}"))
-    }
-    #[cfg(not(feature = "alloc"))]
-    {
-        integer.expect("an integer")
-    }
+    checked_integer(fold_digits(digits(radix), N::zero(), radix, neg), radix)
 }
 
 /// An integer with given radix which allows trailing zeros.
@@ -40,16 +241,91 @@ where
     I: Input<Ok = C> + ?Sized + 'a,
     C: Character + 'a,
 {
-    let integer = fold_digits(digits_trailing_zeros(radix), N::zero(), radix, neg).try_map(
-        |(acc, _, overflowed)| {
-            if overflowed {
-                Err("a not too large number.")
-            } else {
-                Ok(acc)
-            }
-        },
-    );
+    checked_integer(
+        fold_digits(digits_trailing_zeros(radix), N::zero(), radix, neg),
+        radix,
+    )
+}
+
+/// An integer with given radix which has no trailing zeros, separated by `separator` following
+/// `policy`.
+///
+/// # Panics
+/// if `separator` is not an ascii character.
+#[inline]
+pub fn integer_separated<'a, N, I, C>(
+    radix: u8,
+    neg: bool,
+    separator: u8,
+    policy: SeparatorPolicy,
+) -> impl Parser<I, Output = N> + 'a
+where
+    N: Zero + CheckedMul + CheckedAdd + CheckedNeg + TryFrom<u8> + Clone + 'a,
+    I: Input<Ok = C> + ?Sized + 'a,
+    C: Character + 'a,
+{
+    checked_integer(
+        fold_digits(
+            digits_separated(radix, separator, policy),
+            N::zero(),
+            radix,
+            neg,
+        ),
+        radix,
+    )
+}
 
+/// An integer with given radix which allows trailing zeros, separated by `separator` following
+/// `policy`.
+///
+/// # Panics
+/// if `separator` is not an ascii character.
+#[inline]
+pub fn integer_trailing_zeros_separated<'a, N, I, C>(
+    radix: u8,
+    neg: bool,
+    separator: u8,
+    policy: SeparatorPolicy,
+) -> impl Parser<I, Output = N> + 'a
+where
+    N: Zero + CheckedMul + CheckedAdd + CheckedNeg + TryFrom<u8> + Clone + 'a,
+    I: Input<Ok = C> + ?Sized + 'a,
+    C: Character + 'a,
+{
+    checked_integer(
+        fold_digits(
+            digits_trailing_zeros_separated(radix, separator, policy),
+            N::zero(),
+            radix,
+            neg,
+        ),
+        radix,
+    )
+}
+
+/// Rejects overflowed results of [`fold_digits`], expecting an integer with `radix`.
+fn checked_integer<'a, P, N, I, C>(folded: P, radix: u8) -> impl Parser<I, Output = N> + 'a
+where
+    P: Parser<I, Output = (N, usize, bool)> + 'a,
+    N: 'a,
+    I: Input<Ok = C> + ?Sized + 'a,
+    C: Character + 'a,
+{
+    expect_integer(
+        folded
+            .try_map(|(acc, _, overflowed)| check_overflow(acc, overflowed, OverflowPolicy::Error)),
+        radix,
+    )
+}
+
+/// Names errors of `integer` as an integer with `radix`.
+#[cfg_attr(not(feature = "alloc"), allow(unused_variables))]
+fn expect_integer<'a, P, I, C>(integer: P, radix: u8) -> impl Parser<I, Output = P::Output> + 'a
+where
+    P: Parser<I> + 'a,
+    I: Input<Ok = C> + ?Sized + 'a,
+    C: Character + 'a,
+{
     #[cfg(feature = "alloc")]
     {
         integer.expect(alloc::format!("an integer with radix {radix}"))
@@ -60,6 +336,306 @@ where
     }
 }
 
+/// An integer whose radix is specified by a prefix like `0x`, or a decimal integer without
+/// prefixes.
+///
+/// Digits after a prefix may have trailing zeros, like `0x00FF`.
+#[inline]
+pub fn prefixed_integer<'a, N, I, C>(
+    prefixes: RadixPrefixes,
+    neg: bool,
+) -> impl Parser<I, Output = N> + 'a
+where
+    N: Zero + CheckedMul + CheckedAdd + CheckedNeg + TryFrom<u8> + Clone + 'a,
+    I: Input<Ok = C> + ?Sized + 'a,
+    C: Character + 'a,
+{
+    prefixed_integer_inner(prefixes, neg, None).map(|(acc, _)| acc)
+}
+
+/// An integer whose radix is specified by a prefix like [`prefixed_integer`], separated by
+/// `separator` following `policy`.
+///
+/// # Panics
+/// if `separator` is not an ascii character.
+#[inline]
+pub fn prefixed_integer_separated<'a, N, I, C>(
+    prefixes: RadixPrefixes,
+    neg: bool,
+    separator: u8,
+    policy: SeparatorPolicy,
+) -> impl Parser<I, Output = N> + 'a
+where
+    N: Zero + CheckedMul + CheckedAdd + CheckedNeg + TryFrom<u8> + Clone + 'a,
+    I: Input<Ok = C> + ?Sized + 'a,
+    C: Character + 'a,
+{
+    prefixed_integer_inner(prefixes, neg, Some((separator, policy))).map(|(acc, _)| acc)
+}
+
+/// An integer whose radix is specified by a prefix like [`prefixed_integer`], returns the value
+/// together with the radix, like `(255, 16)` for `0xFF`.
+///
+/// Decimal integers without prefixes have the radix `10`, and C-style octal integers have `8`
+/// if `prefixes.legacy_octal` is `true`.
+#[inline]
+pub fn prefixed_integer_with_radix<'a, N, I, C>(
+    prefixes: RadixPrefixes,
+    neg: bool,
+) -> impl Parser<I, Output = (N, u8)> + 'a
+where
+    N: Zero + CheckedMul + CheckedAdd + CheckedNeg + TryFrom<u8> + Clone + 'a,
+    I: Input<Ok = C> + ?Sized + 'a,
+    C: Character + 'a,
+{
+    prefixed_integer_inner(prefixes, neg, None)
+}
+
+/// An integer whose radix is specified by a prefix like [`prefixed_integer_with_radix`],
+/// separated by `separator` following `policy`.
+///
+/// # Panics
+/// if `separator` is not an ascii character.
+#[inline]
+pub fn prefixed_integer_separated_with_radix<'a, N, I, C>(
+    prefixes: RadixPrefixes,
+    neg: bool,
+    separator: u8,
+    policy: SeparatorPolicy,
+) -> impl Parser<I, Output = (N, u8)> + 'a
+where
+    N: Zero + CheckedMul + CheckedAdd + CheckedNeg + TryFrom<u8> + Clone + 'a,
+    I: Input<Ok = C> + ?Sized + 'a,
+    C: Character + 'a,
+{
+    prefixed_integer_inner(prefixes, neg, Some((separator, policy)))
+}
+
+fn prefixed_integer_inner<'a, N, I, C>(
+    prefixes: RadixPrefixes,
+    neg: bool,
+    separator: Option<(u8, SeparatorPolicy)>,
+) -> impl Parser<I, Output = (N, u8)> + 'a
+where
+    N: Zero + CheckedMul + CheckedAdd + CheckedNeg + TryFrom<u8> + Clone + 'a,
+    I: Input<Ok = C> + ?Sized + 'a,
+    C: Character + 'a,
+{
+    let after_prefix = match separator {
+        Some((_, SeparatorPolicy::Between)) if prefixes.separator_after_prefix => Included(1),
+        Some(_) if prefixes.separator_after_prefix => Unbounded,
+        _ => Included(0),
+    };
+    let prefixed = character(b'0')
+        .prefix(is_some(move |c: C| prefixes.radix(&c)))
+        .rewindable()
+        .spanned()
+        .then(move |(radix, expects)| {
+            character(separator.map_or(b'_', |(separator, _)| separator))
+                .repeat((Unbounded, after_prefix))
+                .discard()
+                .prefix(fold_digits(
+                    digits_trailing_zeros_with(radix, separator),
+                    N::zero(),
+                    radix,
+                    neg,
+                ))
+                .map(move |(acc, _, overflowed)| (acc, radix, overflowed))
+                .expect(expects)
+        });
+
+    let decimal = if prefixes.legacy_octal {
+        character(b'0')
+            .prefix(
+                fold_digits(digits_trailing_zeros_with(8, separator), N::zero(), 8, neg)
+                    .map(|(acc, _, overflowed)| (acc, 8, overflowed))
+                    .or(value((N::zero(), 10, false))),
+            )
+            .or(fold_digits(digits_with(10, separator), N::zero(), 10, neg)
+                .map(|(acc, _, overflowed)| (acc, 10, overflowed)))
+            .left()
+    } else if prefixes.leading_zeros {
+        fold_digits(
+            digits_trailing_zeros_with(10, separator),
+            N::zero(),
+            10,
+            neg,
+        )
+        .map(|(acc, _, overflowed)| (acc, 10, overflowed))
+        .left()
+        .right()
+    } else {
+        fold_digits(digits_with(10, separator), N::zero(), 10, neg)
+            .map(|(acc, _, overflowed)| (acc, 10, overflowed))
+            .right()
+            .right()
+    };
+
+    prefixed
+        .or(decimal)
+        .try_map(|(acc, radix, overflowed)| {
+            check_overflow(acc, overflowed, OverflowPolicy::Error).map(|acc| (acc, radix))
+        })
+        .expect("an integer")
+}
+
+/// An arbitrary-precision integer with given radix which has no trailing zeros.
+#[cfg(feature = "bigint")]
+#[cfg_attr(feature = "nightly", doc(cfg(feature = "bigint")))]
+#[inline]
+pub fn big_integer<'a, I, C>(radix: u8, neg: bool) -> impl Parser<I, Output = BigInt> + 'a
+where
+    I: Input<Ok = C> + ?Sized + 'a,
+    C: Character + 'a,
+{
+    fold_big_digits(digits(radix), radix, neg)
+        .expect(alloc::format!("an integer with radix {radix}"))
+}
+
+/// An arbitrary-precision integer with given radix which allows trailing zeros.
+#[cfg(feature = "bigint")]
+#[cfg_attr(feature = "nightly", doc(cfg(feature = "bigint")))]
+#[inline]
+pub fn big_integer_trailing_zeros<'a, I, C>(
+    radix: u8,
+    neg: bool,
+) -> impl Parser<I, Output = BigInt> + 'a
+where
+    I: Input<Ok = C> + ?Sized + 'a,
+    C: Character + 'a,
+{
+    fold_big_digits(digits_trailing_zeros(radix), radix, neg)
+        .expect(alloc::format!("an integer with radix {radix}"))
+}
+
+/// An integer with given radix which has no trailing zeros, returns digits as they are without
+/// converting into a number.
+#[inline]
+pub fn integer_lexeme<'a, E, I, C>(radix: u8) -> impl Parser<I, Output = E> + 'a
+where
+    E: Extend<C> + Default + 'a,
+    I: Input<Ok = C> + ?Sized + 'a,
+    C: Character + 'a,
+{
+    expect_integer(digits(radix).collect(), radix)
+}
+
+/// An integer with given radix which has no trailing zeros, followed by an optional type suffix
+/// like `42u8`, `100L` or `5n`.
+///
+/// `suffixes` is a list of pairs of ascii suffixes and their types, and the type will be
+/// `default` if no suffixes are found. Letters, digits and underscores following the integer are
+/// parsed as a suffix, so unknown suffixes are rejected instead of left unparsed.
+///
+/// # Panics
+/// if `suffixes` has more than 64 elements.
+pub fn suffixed_integer<'a, I, C>(
+    radix: u8,
+    neg: bool,
+    suffixes: &'a [(&'static str, IntType)],
+    default: IntType,
+) -> impl Parser<I, Output = IntLiteral> + 'a
+where
+    I: Input<Ok = C> + ?Sized + 'a,
+    C: Character + 'a,
+{
+    #[cfg(feature = "bigint")]
+    let integer = fold_big_digits(digits(radix), radix, neg)
+        .and(int_suffix(suffixes, default))
+        .try_map(|(value, ty): (BigInt, IntType)| ty.convert_big(value).ok_or(ty.range_expects()));
+    #[cfg(not(feature = "bigint"))]
+    let integer = with_int_suffix(
+        fold_digits(digits(radix), 0u128, radix, false)
+            .try_map(|(acc, _, overflowed)| check_overflow(acc, overflowed, OverflowPolicy::Error)),
+        neg,
+        suffixes,
+        default,
+    );
+    expect_integer(integer, radix)
+}
+
+/// Takes a parser of absolute values of integers, parses an optional type suffix following it like
+/// [`suffixed_integer`], and checks the range of the value.
+///
+/// # Panics
+/// if `suffixes` has more than 64 elements.
+pub fn with_int_suffix<'a, P, I, C>(
+    integer: P,
+    neg: bool,
+    suffixes: &'a [(&'static str, IntType)],
+    default: IntType,
+) -> impl Parser<I, Output = IntLiteral> + 'a
+where
+    P: Parser<I, Output = u128> + 'a,
+    I: Input<Ok = C> + ?Sized + 'a,
+    C: Character + 'a,
+{
+    integer
+        .and(int_suffix(suffixes, default))
+        .try_map(move |(abs, ty): (u128, IntType)| ty.convert(abs, neg).ok_or(ty.range_expects()))
+}
+
+/// Parses an optional type suffix in `suffixes`, or returns `default`.
+fn int_suffix<'a, I, C>(
+    suffixes: &'a [(&'static str, IntType)],
+    default: IntType,
+) -> impl Parser<I, Output = IntType> + 'a
+where
+    I: Input<Ok = C> + ?Sized + 'a,
+    C: Character + 'a,
+{
+    longest_word(
+        suffixes,
+        |c: &C| c.is_alphanumeric() || c.eq_byte(b'_'),
+        false,
+    )
+    .try_map(move |(len, ty)| match (len, ty) {
+        (0, _) => Ok(default),
+        (_, Some(ty)) => Ok(ty),
+        (_, None) => Err("a valid integer suffix"),
+    })
+}
+
+/// An integer with given radix which has no trailing zeros, handling overflows following
+/// `policy`.
+#[inline]
+pub fn integer_overflowing<'a, N, I, C>(
+    radix: u8,
+    neg: bool,
+    policy: OverflowPolicy,
+) -> impl Parser<I, Output = N> + 'a
+where
+    N: OverflowingInt + 'a,
+    I: Input<Ok = C> + ?Sized + 'a,
+    C: Character + 'a,
+{
+    expect_integer(
+        fold_digits_overflowing(digits(radix), N::zero(), radix, neg, policy)
+            .try_map(move |(acc, _, overflowed)| check_overflow(acc, overflowed, policy)),
+        radix,
+    )
+}
+
+/// An integer with given radix which allows trailing zeros, handling overflows following
+/// `policy`.
+#[inline]
+pub fn integer_trailing_zeros_overflowing<'a, N, I, C>(
+    radix: u8,
+    neg: bool,
+    policy: OverflowPolicy,
+) -> impl Parser<I, Output = N> + 'a
+where
+    N: OverflowingInt + 'a,
+    I: Input<Ok = C> + ?Sized + 'a,
+    C: Character + 'a,
+{
+    expect_integer(
+        fold_digits_overflowing(digits_trailing_zeros(radix), N::zero(), radix, neg, policy)
+            .try_map(move |(acc, _, overflowed)| check_overflow(acc, overflowed, policy)),
+        radix,
+    )
+}
+
 /// A fixed-length integer with given radix.
 #[inline]
 pub fn integer_fixed<'a, N, I, C>(
@@ -72,15 +648,35 @@ where
     I: Positioned<Ok = C> + ?Sized + 'a,
     C: Character + 'a,
 {
-    fold_digits(digits_fixed(length, radix), N::zero(), radix, neg).try_map(
-        |(acc, _, overflowed)| {
-            if overflowed {
-                Err("a not too large number.")
-            } else {
-                Ok(acc)
-            }
-        },
-    )
+    fold_digits(digits_fixed(length, radix), N::zero(), radix, neg)
+        .try_map(|(acc, _, overflowed)| check_overflow(acc, overflowed, OverflowPolicy::Error))
+}
+
+/// A fixed-length integer with given radix, handling overflows following `policy`.
+#[inline]
+pub fn integer_fixed_overflowing<'a, N, I, C>(
+    length: usize,
+    radix: u8,
+    neg: bool,
+    policy: OverflowPolicy,
+) -> impl Parser<I, Output = N> + 'a
+where
+    N: OverflowingInt + 'a,
+    I: Positioned<Ok = C> + ?Sized + 'a,
+    C: Character + 'a,
+{
+    fold_digits_overflowing(digits_fixed(length, radix), N::zero(), radix, neg, policy)
+        .try_map(move |(acc, _, overflowed)| check_overflow(acc, overflowed, policy))
+}
+
+/// Rejects overflowed results of [`fold_digits_overflowing`] unless `policy` keeps them.
+fn check_overflow<N>(acc: N, overflowed: bool, policy: OverflowPolicy) -> Result<N, &'static str> {
+    match policy {
+        OverflowPolicy::Error | OverflowPolicy::Float if overflowed => {
+            Err("a not too large number.")
+        }
+        _ => Ok(acc),
+    }
 }
 
 /// Takes a streamed parser of digits, folds it to an `acc` as following digits.
@@ -121,3 +717,67 @@ where
         },
     )
 }
+
+/// Takes a streamed parser of digits, folds it to an `acc` like [`fold_digits`], handling
+/// overflows following `policy`.
+///
+/// The last item of the output will be `true` if the number has overflowed, and the folded result
+/// is saturated or wrapped around if `policy` is [`OverflowPolicy::Saturate`] or
+/// [`OverflowPolicy::Wrap`].
+pub fn fold_digits_overflowing<'a, N, S, I, C>(
+    streamed: S,
+    acc: N,
+    radix: u8,
+    neg: bool,
+    policy: OverflowPolicy,
+) -> impl Parser<I, Output = (N, usize, bool)> + 'a
+where
+    N: OverflowingInt + 'a,
+    S: IterableParser<I, Item = C> + 'a,
+    I: Positioned<Ok = C> + ?Sized + 'a,
+    C: Character + 'a,
+{
+    let stops = matches!(policy, OverflowPolicy::Error | OverflowPolicy::Float);
+    streamed.fold(
+        value((acc, 0, false)),
+        move |(acc, count, overflowed), x: C| {
+            if overflowed && stops {
+                return (acc, count, true);
+            }
+            let (acc, res) = push_digit(acc, x.to_digit(radix).unwrap(), radix, neg, policy);
+            if res && stops {
+                (acc, count, true)
+            } else {
+                (acc, count + 1, overflowed || res)
+            }
+        },
+    )
+}
+
+/// Takes a streamed parser of digits, converts it into an arbitrary-precision integer.
+///
+/// Unlike [`fold_digits`], this function never overflows.
+#[cfg(feature = "bigint")]
+#[cfg_attr(feature = "nightly", doc(cfg(feature = "bigint")))]
+pub fn fold_big_digits<'a, S, I, C>(
+    streamed: S,
+    radix: u8,
+    neg: bool,
+) -> impl Parser<I, Output = BigInt> + 'a
+where
+    S: IterableParser<I, Item = C> + 'a,
+    I: Positioned<Ok = C> + ?Sized + 'a,
+    C: Character + 'a,
+{
+    streamed
+        .map(move |c| c.to_digit(radix).unwrap())
+        .collect::<alloc::vec::Vec<_>>()
+        .map(move |digits| {
+            let sign = if neg {
+                num_bigint::Sign::Minus
+            } else {
+                num_bigint::Sign::Plus
+            };
+            BigInt::from_radix_be(sign, &digits, radix as u32).unwrap()
+        })
+}
@@ -0,0 +1,110 @@
+//! Parsers for rational numbers.
+use num_traits::{CheckedAdd, CheckedDiv, CheckedMul, CheckedNeg, CheckedRem, One, Zero};
+use somen::prelude::*;
+
+use super::integer::integer;
+use crate::character::{characters, Character};
+
+#[cfg(feature = "rational")]
+#[cfg_attr(feature = "nightly", doc(cfg(feature = "rational")))]
+#[doc(no_inline)]
+pub use num_rational::Ratio;
+
+/// A rational number like `3/4`, returns a numerator and a denominator.
+///
+/// Both of them are integers with given radix which have no trailing zeros, separated by
+/// `separator` like `/` or `//`. If the separator is missing, the denominator will be `1`.
+/// Zero denominators are rejected, and the fraction is reduced to the lowest terms with a
+/// positive denominator if `reduce` is `true`.
+///
+/// # Panics
+/// if `separator` contains non-ascii characters.
+pub fn rational<'a, N, I, C>(
+    radix: u8,
+    neg: bool,
+    separator: &'static str,
+    reduce: bool,
+) -> impl Parser<I, Output = (N, N)> + 'a
+where
+    N: Zero
+        + One
+        + CheckedMul
+        + CheckedAdd
+        + CheckedNeg
+        + CheckedDiv
+        + CheckedRem
+        + TryFrom<u8>
+        + PartialOrd
+        + Clone
+        + 'a,
+    I: Input<Ok = C> + ?Sized + 'a,
+    C: Character + 'a,
+{
+    let denominator = integer(radix, false).try_map(|den: N| {
+        if den.is_zero() {
+            Err("a non-zero denominator")
+        } else {
+            Ok(den)
+        }
+    });
+
+    integer(radix, neg)
+        .and(characters(separator).prefix(denominator).opt())
+        .try_map(move |(num, den): (N, Option<N>)| {
+            let den = den.unwrap_or_else(N::one);
+            if reduce {
+                reduce_fraction(num, den).ok_or("a not too large number.")
+            } else {
+                Ok((num, den))
+            }
+        })
+}
+
+/// A rational number like [`rational`], always reduced to the lowest terms.
+///
+/// # Panics
+/// if `separator` contains non-ascii characters.
+#[cfg(feature = "rational")]
+#[cfg_attr(feature = "nightly", doc(cfg(feature = "rational")))]
+#[inline]
+pub fn ratio<'a, N, I, C>(
+    radix: u8,
+    neg: bool,
+    separator: &'static str,
+) -> impl Parser<I, Output = Ratio<N>> + 'a
+where
+    N: Zero
+        + One
+        + CheckedMul
+        + CheckedAdd
+        + CheckedNeg
+        + CheckedDiv
+        + CheckedRem
+        + TryFrom<u8>
+        + PartialOrd
+        + Clone
+        + 'a,
+    I: Input<Ok = C> + ?Sized + 'a,
+    C: Character + 'a,
+{
+    rational(radix, neg, separator, true).map(|(num, den)| Ratio::new_raw(num, den))
+}
+
+fn reduce_fraction<N>(num: N, den: N) -> Option<(N, N)>
+where
+    N: Zero + CheckedNeg + CheckedDiv + CheckedRem + PartialOrd + Clone,
+{
+    let (mut a, mut b) = (den.clone(), num.clone());
+    while !b.is_zero() {
+        let r = a.checked_rem(&b)?;
+        a = b;
+        b = r;
+    }
+
+    let (num, den) = (num.checked_div(&a)?, den.checked_div(&a)?);
+    if den < N::zero() {
+        Some((num.checked_neg()?, den.checked_neg()?))
+    } else {
+        Some((num, den))
+    }
+}
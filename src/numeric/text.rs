@@ -0,0 +1,468 @@
+//! Parsers for numbers written in letters, like Roman numerals and English words.
+use num_traits::{CheckedAdd, CheckedMul, CheckedNeg, Zero};
+use somen::prelude::*;
+
+use super::integer::fold_digits;
+use super::{digits, longest_word};
+use crate::character::Character;
+
+/// A Roman numeral in the strict subtractive form, like `XIV` or `MCMXCIX`.
+///
+/// Only numbers from `1` to `3999` are representable, and non-canonical forms like `IIII`, `IC`
+/// or `VX` are rejected.
+pub fn roman<'a, N, I, C>(case_insensitive: bool) -> impl Parser<I, Output = N> + 'a
+where
+    N: Zero + CheckedMul + CheckedAdd + CheckedNeg + TryFrom<u8> + Clone + 'a,
+    I: Input<Ok = C> + ?Sized + 'a,
+    C: Character + 'a,
+{
+    roman_letter(b'M', case_insensitive)
+        .repeat(..4)
+        .count()
+        .and(roman_place(*b"CDM", case_insensitive))
+        .and(roman_place(*b"XLC", case_insensitive))
+        .and(roman_place(*b"IVX", case_insensitive))
+        .skip(
+            is(move |c: &C| {
+                b"IVXLCDM"
+                    .iter()
+                    .any(|b| eq_letter(c, *b, case_insensitive))
+            })
+            .fail(),
+        )
+        .try_map(|(((thousands, hundreds), tens), ones)| {
+            match thousands as u64 * 1000 + hundreds * 100 + tens * 10 + ones {
+                0 => Err("a roman numeral"),
+                n => Ok(n),
+            }
+        })
+        .rewindable()
+        .spanned()
+        .expect("a roman numeral")
+        .try_map(|n| from_u64(n).ok_or("a not too large number."))
+}
+
+/// Parses a digit of a Roman numeral, with letters of one, five and ten of the place.
+fn roman_place<'a, I, C>(
+    [one, five, ten]: [u8; 3],
+    case_insensitive: bool,
+) -> impl Parser<I, Output = u64> + 'a
+where
+    I: Input<Ok = C> + ?Sized + 'a,
+    C: Character + 'a,
+{
+    let ones = roman_letter(one, case_insensitive).prefix(
+        roman_letter(ten, case_insensitive)
+            .map(|_| 9)
+            .or(roman_letter(five, case_insensitive).map(|_| 4))
+            .or(roman_letter(one, case_insensitive)
+                .repeat(..3)
+                .count()
+                .map(|n| n as u64 + 1)),
+    );
+    let fives = roman_letter(five, case_insensitive).prefix(
+        roman_letter(one, case_insensitive)
+            .repeat(..4)
+            .count()
+            .map(|n| n as u64 + 5),
+    );
+    ones.or(fives).or(value(0))
+}
+
+fn roman_letter<'a, I, C>(letter: u8, case_insensitive: bool) -> impl Parser<I, Output = C> + 'a
+where
+    I: Input<Ok = C> + ?Sized + 'a,
+    C: Character + 'a,
+{
+    is(move |c: &C| eq_letter(c, letter, case_insensitive))
+}
+
+fn eq_letter<C: Character>(c: &C, letter: u8, case_insensitive: bool) -> bool {
+    if case_insensitive {
+        c.eq_byte_ignore_case(letter)
+    } else {
+        c.eq_byte(letter)
+    }
+}
+
+/// A word of English cardinal numbers.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Word {
+    Zero,
+    Unit(u64),
+    Teen(u64),
+    Tens(u64),
+    Hundred,
+    Scale(u64),
+    And,
+}
+
+const WORDS: &[(&str, Word)] = &[
+    ("zero", Word::Zero),
+    ("one", Word::Unit(1)),
+    ("two", Word::Unit(2)),
+    ("three", Word::Unit(3)),
+    ("four", Word::Unit(4)),
+    ("five", Word::Unit(5)),
+    ("six", Word::Unit(6)),
+    ("seven", Word::Unit(7)),
+    ("eight", Word::Unit(8)),
+    ("nine", Word::Unit(9)),
+    ("ten", Word::Teen(10)),
+    ("eleven", Word::Teen(11)),
+    ("twelve", Word::Teen(12)),
+    ("thirteen", Word::Teen(13)),
+    ("fourteen", Word::Teen(14)),
+    ("fifteen", Word::Teen(15)),
+    ("sixteen", Word::Teen(16)),
+    ("seventeen", Word::Teen(17)),
+    ("eighteen", Word::Teen(18)),
+    ("nineteen", Word::Teen(19)),
+    ("twenty", Word::Tens(20)),
+    ("thirty", Word::Tens(30)),
+    ("forty", Word::Tens(40)),
+    ("fifty", Word::Tens(50)),
+    ("sixty", Word::Tens(60)),
+    ("seventy", Word::Tens(70)),
+    ("eighty", Word::Tens(80)),
+    ("ninety", Word::Tens(90)),
+    ("hundred", Word::Hundred),
+    ("thousand", Word::Scale(1_000)),
+    ("million", Word::Scale(1_000_000)),
+    ("billion", Word::Scale(1_000_000_000)),
+    ("trillion", Word::Scale(1_000_000_000_000)),
+    ("quadrillion", Word::Scale(1_000_000_000_000_000)),
+    ("quintillion", Word::Scale(1_000_000_000_000_000_000)),
+    ("and", Word::And),
+];
+
+/// A state of parsing cardinal numbers.
+///
+/// Values are accumulated in `u128`, which never overflows because the largest number in words
+/// is less than a thousand quintillions.
+#[derive(Clone, Copy, Debug)]
+struct Cardinal {
+    total: u128,
+    group: u128,
+    scale: u128,
+    prev: Option<Word>,
+}
+
+impl Cardinal {
+    const fn new() -> Self {
+        Self {
+            total: 0,
+            group: 0,
+            scale: u128::MAX,
+            prev: None,
+        }
+    }
+
+    /// Appends a word, or returns [`None`] if the word is not valid in this position.
+    fn push(mut self, hyphen: bool, word: Word) -> Option<Self> {
+        let prev = self.prev;
+        let after_group = matches!(
+            prev,
+            None | Some(Word::Hundred) | Some(Word::Scale(_)) | Some(Word::And)
+        );
+        let valid = match word {
+            Word::Zero => prev.is_none(),
+            Word::Unit(n) => {
+                self.group += n as u128;
+                matches!(prev, Some(Word::Tens(_))) || (after_group && !hyphen)
+            }
+            Word::Teen(n) | Word::Tens(n) => {
+                self.group += n as u128;
+                after_group && !hyphen
+            }
+            Word::Hundred => {
+                self.group *= 100;
+                matches!(prev, Some(Word::Unit(_))) && self.group < 1000 && !hyphen
+            }
+            Word::Scale(scale) => {
+                let scale = scale as u128;
+                self.total += self.group * scale;
+                self.group = 0;
+                let valid = matches!(
+                    prev,
+                    Some(Word::Unit(_) | Word::Teen(_) | Word::Tens(_) | Word::Hundred)
+                ) && scale < self.scale
+                    && !hyphen;
+                self.scale = scale;
+                valid
+            }
+            Word::And => matches!(prev, Some(Word::Hundred | Word::Scale(_))) && !hyphen,
+        };
+        if valid {
+            self.prev = Some(word);
+            Some(self)
+        } else {
+            None
+        }
+    }
+
+    /// Returns the value, or [`None`] if the number cannot end here.
+    fn finish(self) -> Option<u128> {
+        match self.prev {
+            Some(Word::And) => None,
+            _ => Some(self.total + self.group),
+        }
+    }
+}
+
+/// A state of scanning words ahead, the longest valid number so far and its count of words.
+#[derive(Clone, Copy, Debug)]
+struct Scan {
+    state: Option<Cardinal>,
+    count: usize,
+    longest: Option<(usize, u128)>,
+}
+
+/// An English cardinal number in words, like `zero`, `twenty-one` or
+/// `one hundred and five thousand`.
+///
+/// Words are separated by single spaces, except that units following tens can be joined by a
+/// hyphen. Scales up to `quintillion` are supported and must appear in descending order.
+///
+/// The longest valid number is parsed, and the following words are left unparsed, so
+/// `five and six` is `5` followed by ` and six`.
+pub fn cardinal<'a, N, I, C>(case_insensitive: bool) -> impl Parser<I, Output = N> + 'a
+where
+    N: Zero + CheckedMul + CheckedAdd + CheckedNeg + TryFrom<u8> + Clone + 'a,
+    I: Input<Ok = C> + ?Sized + 'a,
+    C: Character + 'a,
+{
+    let word = move || {
+        longest_word(WORDS, C::is_alphabetic, case_insensitive).try_map(|(_, word)| match word {
+            Some(word) => Ok(word),
+            None => Err("a number word"),
+        })
+    };
+    let separated = move || {
+        is(|c: &C| c.eq_byte(b' ') || c.eq_byte(b'-'))
+            .and(word())
+            .rewindable()
+            .spanned()
+            .map(|(sep, word): (C, Word)| (sep.eq_byte(b'-'), word))
+    };
+    let scan = Scan {
+        state: Some(Cardinal::new()),
+        count: 0,
+        longest: None,
+    };
+
+    // Scans words ahead to find the longest valid number, and parses just its words.
+    word()
+        .map(|word| (false, word))
+        .once()
+        .chain(separated().repeat(..))
+        .fold(value(scan), |scan, (hyphen, word)| {
+            match scan.state.and_then(|state| state.push(hyphen, word)) {
+                Some(state) => Scan {
+                    state: Some(state),
+                    count: scan.count + 1,
+                    longest: state.finish().map(|n| (scan.count + 1, n)).or(scan.longest),
+                },
+                None => Scan {
+                    state: None,
+                    ..scan
+                },
+            }
+        })
+        .try_map(|scan| scan.longest.ok_or("a number word in a valid position"))
+        .rewindable()
+        .spanned()
+        .peek()
+        .then(move |(count, n)| {
+            word()
+                .prefix(separated().times(count - 1).discard())
+                .map(move |_| n)
+        })
+        .try_map(|n| {
+            u64::try_from(n)
+                .ok()
+                .and_then(from_u64)
+                .ok_or("a not too large number.")
+        })
+        .expect("a number in words")
+}
+
+/// A decimal integer followed by an English ordinal suffix, like `1st`, `22nd` or `13th`.
+///
+/// The suffix must agree with the number, so `11st` and `3th` are rejected.
+pub fn ordinal<'a, N, I, C>(case_insensitive: bool) -> impl Parser<I, Output = N> + 'a
+where
+    N: Zero + CheckedMul + CheckedAdd + CheckedNeg + TryFrom<u8> + Clone + 'a,
+    I: Input<Ok = C> + ?Sized + 'a,
+    C: Character + 'a,
+{
+    const SUFFIXES: &[(&str, usize)] = &[("th", 0), ("st", 1), ("nd", 2), ("rd", 3)];
+    const EXPECTS: [&str; 4] = [
+        "an ordinal suffix `th`",
+        "an ordinal suffix `st`",
+        "an ordinal suffix `nd`",
+        "an ordinal suffix `rd`",
+    ];
+
+    let last_digits = digits(10)
+        .fold(value(0u8), |last, c: C| {
+            last % 10 * 10 + c.to_digit(10).unwrap()
+        })
+        .peek();
+    let integer = fold_digits(digits(10), N::zero(), 10, false).try_map(|(acc, _, overflowed)| {
+        if overflowed {
+            Err("a not too large number.")
+        } else {
+            Ok(acc)
+        }
+    });
+
+    last_digits
+        .and(integer)
+        .and(longest_word(SUFFIXES, C::is_alphabetic, case_insensitive))
+        .try_map(|((last, acc), (_, suffix))| {
+            let expected = match (last / 10, last % 10) {
+                (1, _) | (_, 4..=9) | (_, 0) => 0,
+                (_, n) => n as usize,
+            };
+            if suffix == Some(expected) {
+                Ok(acc)
+            } else {
+                Err(EXPECTS[expected])
+            }
+        })
+        .expect("an ordinal number")
+}
+
+/// Converts a `u64` into `N` with only checked operations.
+fn from_u64<N>(n: u64) -> Option<N>
+where
+    N: Zero + CheckedMul + CheckedAdd + TryFrom<u8> + Clone,
+{
+    let ten = N::try_from(10).ok()?;
+    let mut div = 1;
+    while n / div >= 10 {
+        div *= 10;
+    }
+    let mut acc = N::zero();
+    loop {
+        let digit = N::try_from((n / div % 10) as u8).ok()?;
+        acc = acc.checked_mul(&ten)?.checked_add(&digit)?;
+        if div == 1 {
+            break Some(acc);
+        }
+        div /= 10;
+    }
+}
+
+#[cfg(all(test, feature = "alloc"))]
+mod tests {
+    use super::*;
+    use alloc::string::String;
+    use somen::stream;
+
+    macro_rules! parse {
+        ($parser:expr, $input:expr) => {
+            futures_executor::block_on(async {
+                let mut stream = stream::from_iter($input.chars())
+                    .positioned::<usize>()
+                    .buffered_rewind();
+                $parser
+                    .and(any().repeat(..).collect::<String>())
+                    .complete()
+                    .parse(&mut stream)
+                    .await
+                    .ok()
+            })
+        };
+    }
+
+    #[test]
+    fn roman_numerals() {
+        for (input, expected, rest) in [
+            ("I", 1, ""),
+            ("XIV", 14, ""),
+            ("XL", 40, ""),
+            ("MCMXCIX", 1999, ""),
+            ("MMMCMXCIX", 3999, ""),
+            ("XIV ", 14, " "),
+            ("XIVa", 14, "a"),
+        ] {
+            assert_eq!(
+                parse!(roman::<u32, _, _>(false), input),
+                Some((expected, String::from(rest))),
+                "{input}"
+            );
+        }
+        assert_eq!(
+            parse!(roman::<u32, _, _>(true), "mcmxcix"),
+            Some((1999, String::new()))
+        );
+        for input in ["", "IIII", "IC", "VX", "MMMM", "xiv"] {
+            assert_eq!(parse!(roman::<u32, _, _>(false), input), None, "{input}");
+        }
+    }
+
+    #[test]
+    fn cardinals() {
+        for (input, expected, rest) in [
+            ("zero", 0, ""),
+            ("twenty-one", 21, ""),
+            ("one hundred and five", 105, ""),
+            ("one hundred and five thousand", 105_000, ""),
+            ("two million three hundred thousand", 2_300_000, ""),
+            ("eighteen quintillion", 18_000_000_000_000_000_000, ""),
+            ("five and six", 5, " and six"),
+            ("five and", 5, " and"),
+            ("five hundred and", 500, " and"),
+            ("five six", 5, " six"),
+            ("five-six", 5, "-six"),
+            ("zero one", 0, " one"),
+            ("one thousand one thousand", 1001, " thousand"),
+            ("seven apples", 7, " apples"),
+        ] {
+            assert_eq!(
+                parse!(cardinal::<u64, _, _>(false), input),
+                Some((expected, String::from(rest))),
+                "{input}"
+            );
+        }
+        assert_eq!(
+            parse!(cardinal::<u64, _, _>(true), "Forty-Two"),
+            Some((42, String::new()))
+        );
+        for input in ["", "and", "hundred", "apples", "nineteen quintillion"] {
+            assert_eq!(parse!(cardinal::<u64, _, _>(false), input), None, "{input}");
+        }
+    }
+
+    #[test]
+    fn ordinals() {
+        for (input, expected, rest) in [
+            ("1st", 1, ""),
+            ("2nd", 2, ""),
+            ("3rd", 3, ""),
+            ("4th", 4, ""),
+            ("11th", 11, ""),
+            ("12th", 12, ""),
+            ("13th", 13, ""),
+            ("22nd", 22, ""),
+            ("101st", 101, ""),
+            ("112th", 112, ""),
+            ("4th!", 4, "!"),
+        ] {
+            assert_eq!(
+                parse!(ordinal::<u32, _, _>(false), input),
+                Some((expected, String::from(rest))),
+                "{input}"
+            );
+        }
+        assert_eq!(
+            parse!(ordinal::<u32, _, _>(true), "21ST"),
+            Some((21, String::new()))
+        );
+        for input in ["11st", "3th", "1nd", "1", "st", "1sts"] {
+            assert_eq!(parse!(ordinal::<u32, _, _>(false), input), None, "{input}");
+        }
+    }
+}
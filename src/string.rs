@@ -0,0 +1,1524 @@
+//! Parsers for string literals.
+#[cfg(feature = "alloc")]
+use alloc::{string::String, vec, vec::Vec};
+use somen::prelude::*;
+
+use crate::character::{character, characters, Character};
+use crate::numeric::integer::fold_digits;
+
+/// A table of escape sequences starting with a backslash.
+///
+/// By the default, no escape sequences are allowed, set the sequences you want or start from one
+/// of the presets like [`json`] or [`rust`].
+///
+/// [`json`]: Self::json
+/// [`rust`]: Self::rust
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Escapes {
+    simple: &'static [(u8, char)],
+    octal: Option<u16>,
+    truncate_octal: bool,
+    hex: Option<u8>,
+    unicode_braced: bool,
+    unicode_short: bool,
+    surrogate_pairs: bool,
+    unicode_long: bool,
+}
+
+impl Default for Escapes {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Escapes {
+    /// Creates an empty table.
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            simple: &[],
+            octal: None,
+            truncate_octal: false,
+            hex: None,
+            unicode_braced: false,
+            unicode_short: false,
+            surrogate_pairs: false,
+            unicode_long: false,
+        }
+    }
+
+    /// Escape sequences of JSON: `\"`, `\\`, `\/`, `\b`, `\f`, `\n`, `\r`, `\t` and `\uXXXX`.
+    #[inline]
+    pub const fn json() -> Self {
+        Self::new()
+            .simple(&[
+                (b'"', '"'),
+                (b'\\', '\\'),
+                (b'/', '/'),
+                (b'b', '\x08'),
+                (b'f', '\x0c'),
+                (b'n', '\n'),
+                (b'r', '\r'),
+                (b't', '\t'),
+            ])
+            .unicode_short(true)
+            .surrogate_pairs(true)
+    }
+
+    /// Escape sequences of Rust: `\n`, `\r`, `\t`, `\\`, `\0`, `\'`, `\"`, `\xNN` up to `\x7F`
+    /// and `\u{...}`.
+    #[inline]
+    pub const fn rust() -> Self {
+        Self::new()
+            .simple(&[
+                (b'n', '\n'),
+                (b'r', '\r'),
+                (b't', '\t'),
+                (b'\\', '\\'),
+                (b'0', '\0'),
+                (b'\'', '\''),
+                (b'"', '"'),
+            ])
+            .hex(0x7F)
+            .unicode_braced(true)
+    }
+
+    /// Escape sequences of Java: `\b`, `\t`, `\n`, `\f`, `\r`, `\s`, `\"`, `\'`, `\\` and octal
+    /// escapes up to `\377`, where `\477` is `\47` followed by `7`.
+    ///
+    /// Note that unicode escapes of Java are processed before parsing, so they are not included.
+    #[inline]
+    pub const fn java() -> Self {
+        Self::new()
+            .simple(&[
+                (b'b', '\x08'),
+                (b't', '\t'),
+                (b'n', '\n'),
+                (b'f', '\x0c'),
+                (b'r', '\r'),
+                (b's', ' '),
+                (b'"', '"'),
+                (b'\'', '\''),
+                (b'\\', '\\'),
+            ])
+            .octal(0o377)
+            .truncate_octal(true)
+    }
+
+    /// Escape sequences of Swift: `\0`, `\\`, `\t`, `\n`, `\r`, `\"`, `\'` and `\u{...}`.
+    #[inline]
+    pub const fn swift() -> Self {
+        Self::new()
+            .simple(&[
+                (b'0', '\0'),
+                (b'\\', '\\'),
+                (b't', '\t'),
+                (b'n', '\n'),
+                (b'r', '\r'),
+                (b'"', '"'),
+                (b'\'', '\''),
+            ])
+            .unicode_braced(true)
+    }
+
+    /// Escape sequences of C: `\a`, `\b`, `\f`, `\n`, `\r`, `\t`, `\v`, `\\`, `\'`, `\"`, `\?`,
+    /// octal escapes up to `\377`, `\xNN`, `\uXXXX` and `\UXXXXXXXX`.
+    ///
+    /// Surrogates like `\uD83D` are rejected.
+    #[inline]
+    pub const fn c() -> Self {
+        Self::new()
+            .simple(&[
+                (b'a', '\x07'),
+                (b'b', '\x08'),
+                (b'f', '\x0c'),
+                (b'n', '\n'),
+                (b'r', '\r'),
+                (b't', '\t'),
+                (b'v', '\x0b'),
+                (b'\\', '\\'),
+                (b'\'', '\''),
+                (b'"', '"'),
+                (b'?', '?'),
+            ])
+            .octal(0o377)
+            .hex(0xFF)
+            .unicode_short(true)
+            .unicode_long(true)
+    }
+
+    /// Escape sequences of Python: the same as [`c`] except `\?`, and octal escapes up to `\777`.
+    ///
+    /// Note that named escapes like `\N{DASH}` are not supported.
+    ///
+    /// [`c`]: Self::c
+    #[inline]
+    pub const fn python() -> Self {
+        Self::c().octal(0o777).simple(&[
+            (b'a', '\x07'),
+            (b'b', '\x08'),
+            (b'f', '\x0c'),
+            (b'n', '\n'),
+            (b'r', '\r'),
+            (b't', '\t'),
+            (b'v', '\x0b'),
+            (b'\\', '\\'),
+            (b'\'', '\''),
+            (b'"', '"'),
+        ])
+    }
+
+    /// Sets single-character escapes, pairs of an ascii character following a backslash and the
+    /// unescaped character.
+    #[inline]
+    pub const fn simple(mut self, simple: &'static [(u8, char)]) -> Self {
+        self.simple = simple;
+        self
+    }
+
+    /// Allows octal escapes of one to three digits like `\0` or `\177`, up to `max`. (default: not
+    /// allowed)
+    ///
+    /// Octal escapes take precedence over simple escapes starting with digits.
+    #[inline]
+    pub const fn octal(mut self, max: u16) -> Self {
+        self.octal = Some(max);
+        self
+    }
+
+    /// If `true`, an octal escape ends before a digit which makes it larger than the maximum, like
+    /// `\477` in Java which is `\47` followed by `7`. Otherwise such escapes are rejected.
+    /// (default: `false`)
+    #[inline]
+    pub const fn truncate_octal(mut self, truncate: bool) -> Self {
+        self.truncate_octal = truncate;
+        self
+    }
+
+    /// Allows hexadecimal escapes of two digits like `\x7F`, up to `max`. (default: not allowed)
+    #[inline]
+    pub const fn hex(mut self, max: u8) -> Self {
+        self.hex = Some(max);
+        self
+    }
+
+    /// Allows unicode escapes of one to six hexadecimal digits in braces like `\u{1F600}`.
+    /// (default: `false`)
+    #[inline]
+    pub const fn unicode_braced(mut self, allowed: bool) -> Self {
+        self.unicode_braced = allowed;
+        self
+    }
+
+    /// Allows unicode escapes of four hexadecimal digits like `\u00E9`. (default: `false`)
+    ///
+    /// Surrogates are rejected unless [`surrogate_pairs`] is enabled.
+    ///
+    /// [`surrogate_pairs`]: Self::surrogate_pairs
+    #[inline]
+    pub const fn unicode_short(mut self, allowed: bool) -> Self {
+        self.unicode_short = allowed;
+        self
+    }
+
+    /// Joins surrogate pairs of four-digit unicode escapes like `\uD83D\uDE00` into one character
+    /// like JSON, unpaired surrogates are still rejected. (default: `false`)
+    #[inline]
+    pub const fn surrogate_pairs(mut self, allowed: bool) -> Self {
+        self.surrogate_pairs = allowed;
+        self
+    }
+
+    /// Allows unicode escapes of eight hexadecimal digits like `\U0001F600`. (default: `false`)
+    #[inline]
+    pub const fn unicode_long(mut self, allowed: bool) -> Self {
+        self.unicode_long = allowed;
+        self
+    }
+}
+
+/// Parses an escape sequence starting with a backslash following `escapes`, returns the
+/// unescaped character.
+pub fn escape<'a, I, C>(escapes: Escapes) -> impl Parser<I, Output = char> + 'a
+where
+    I: Input<Ok = C> + ?Sized + 'a,
+    C: Character + 'a,
+{
+    let max = escapes.octal.unwrap_or(0) as u32;
+    let octal_digit = move |acc: u32| {
+        is(move |c: &C| {
+            c.is_digit(8)
+                && (!escapes.truncate_octal || acc * 8 + c.to_digit(8).unwrap() as u32 <= max)
+        })
+        .map(move |c: C| acc * 8 + c.to_digit(8).unwrap() as u32)
+        .or(value(acc))
+    };
+    let octal = is(move |c: &C| escapes.octal.is_some() && c.is_digit(8))
+        .map(|c: C| c.to_digit(8).unwrap() as u32)
+        .then(octal_digit)
+        .then(octal_digit)
+        .try_map(move |code| {
+            if code <= max {
+                Ok(char::from_u32(code).unwrap())
+            } else {
+                Err("a not too large character code")
+            }
+        });
+
+    let simple =
+        is(move |c: &C| escapes.simple.iter().any(|(b, _)| c.eq_byte(*b))).map(move |c: C| {
+            escapes
+                .simple
+                .iter()
+                .find(|(b, _)| c.eq_byte(*b))
+                .unwrap()
+                .1
+        });
+
+    let hex = is(move |c: &C| escapes.hex.is_some() && c.eq_byte(b'x'))
+        .prefix(hex_digits(2..=2))
+        .try_map(move |code| match escapes.hex {
+            Some(max) if code <= max as u32 => Ok(char::from_u32(code).unwrap()),
+            _ => Err("a not too large character code"),
+        });
+
+    let braced = is(move |c: &C| escapes.unicode_braced && c.eq_byte(b'u'))
+        .skip(character(b'{'))
+        .rewindable()
+        .spanned()
+        .prefix(hex_digits(1..=6))
+        .skip(character(b'}'))
+        .try_map(|code| char::from_u32(code).ok_or("a valid unicode codepoint"));
+
+    let short = is(move |c: &C| escapes.unicode_short && c.eq_byte(b'u'))
+        .prefix(hex_digits(4..=4))
+        .then(move |high| {
+            if escapes.surrogate_pairs && (0xD800..0xDC00).contains(&high) {
+                character(b'\\')
+                    .prefix(character(b'u'))
+                    .prefix(hex_digits(4..=4))
+                    .expect("a low surrogate escape")
+                    .try_map(move |low| {
+                        if (0xDC00..0xE000).contains(&low) {
+                            Ok(0x10000 + ((high - 0xD800) << 10) + (low - 0xDC00))
+                        } else {
+                            Err("a low surrogate")
+                        }
+                    })
+                    .left()
+            } else {
+                value(high).right()
+            }
+        })
+        .try_map(|code| char::from_u32(code).ok_or("a valid unicode codepoint"));
+
+    let long = is(move |c: &C| escapes.unicode_long && c.eq_byte(b'U'))
+        .prefix(hex_digits(8..=8))
+        .try_map(|code| char::from_u32(code).ok_or("a valid unicode codepoint"));
+
+    character(b'\\').prefix(
+        octal
+            .or(simple)
+            .or(hex)
+            .or(braced)
+            .or(short)
+            .or(long)
+            .expect("a valid escape sequence"),
+    )
+}
+
+/// Parses hexadecimal digits and returns the value.
+fn hex_digits<'a, R, I, C>(range: R) -> impl Parser<I, Output = u32> + 'a
+where
+    R: core::ops::RangeBounds<usize> + 'a,
+    I: Input<Ok = C> + ?Sized + 'a,
+    C: Character + 'a,
+{
+    fold_digits(
+        is(|c: &C| c.is_digit(16))
+            .expect("a hex digit")
+            .repeat(range),
+        0u32,
+        16,
+        false,
+    )
+    .map(|(code, _, _)| code)
+}
+
+/// A quoted string starts and ends with one of `quotes`, containing escape sequences following
+/// `escapes`.
+///
+/// The closing quote must be the same as the opening one, so `'a"b'` is a string `a"b` if
+/// `quotes` is `b"'\""`. Characters are converted by [`Into<char>`], so each byte of `u8` inputs
+/// is treated as a Latin-1 character.
+///
+/// # Panics
+/// if `quotes` contains non-ascii characters.
+pub fn string<'a, E, I, C>(
+    quotes: &'static [u8],
+    escapes: Escapes,
+) -> impl Parser<I, Output = E> + 'a
+where
+    E: Extend<char> + Default + 'a,
+    I: Input<Ok = C> + ?Sized + 'a,
+    C: Character + Into<char> + 'a,
+{
+    assert!(quotes.is_ascii());
+    is(move |c: &C| quotes.iter().any(|b| c.eq_byte(*b)))
+        .then(move |quote: C| {
+            let close = *quotes.iter().find(|b| quote.eq_byte(**b)).unwrap();
+            is_not(move |c: &C| c.eq_byte(close) || c.eq_byte(b'\\'))
+                .map(Into::into)
+                .or(escape(escapes))
+                .expect("a character")
+                .repeat(..)
+                .collect()
+                .skip(character(close))
+        })
+        .expect("a string")
+}
+
+/// A character literal quoted by single quotes, containing exactly one character or an escape
+/// sequence following `escapes`, like `'a'`, `'\n'` or `'\u{1F600}'`.
+///
+/// Characters are converted by [`Into<char>`] like [`string`].
+pub fn char_literal<'a, I, C>(escapes: Escapes) -> impl Parser<I, Output = char> + 'a
+where
+    I: Input<Ok = C> + ?Sized + 'a,
+    C: Character + Into<char> + 'a,
+{
+    quoted_char(escapes)
+        .map(|(c, _)| c)
+        .expect("a character literal")
+}
+
+/// A byte literal, `prefix` followed by a character literal like `b'a'` or `b'\x7F'`.
+///
+/// Unescaped characters must be ascii characters, and escaped ones must be in range of `0x00` to
+/// `0xFF`. Note that [`Escapes::rust`] only allows `\x` escapes up to `0x7F`, set [`Escapes::hex`]
+/// to allow more.
+///
+/// # Panics
+/// if `prefix` contains non-ascii characters.
+pub fn byte_literal<'a, I, C>(
+    prefix: &'static str,
+    escapes: Escapes,
+) -> impl Parser<I, Output = u8> + 'a
+where
+    I: Input<Ok = C> + ?Sized + 'a,
+    C: Character + Into<char> + 'a,
+{
+    characters(prefix)
+        .prefix(quoted_char(escapes))
+        .try_map(|(c, escaped)| match u8::try_from(c) {
+            Ok(b) if escaped || b.is_ascii() => Ok(b),
+            _ if escaped => Err("a byte"),
+            _ => Err("an ascii character"),
+        })
+        .expect("a byte literal")
+}
+
+/// Parses a character quoted by single quotes, returns it and `true` if it is escaped.
+fn quoted_char<'a, I, C>(escapes: Escapes) -> impl Parser<I, Output = (char, bool)> + 'a
+where
+    I: Input<Ok = C> + ?Sized + 'a,
+    C: Character + Into<char> + 'a,
+{
+    let item = is_not(|c: &C| c.eq_byte(b'\'') || c.eq_byte(b'\\') || c.eq_byte(b'\n'))
+        .map(|c: C| (c.into(), false))
+        .or(escape(escapes).map(|c| (c, true)))
+        .expect("a character");
+
+    character(b'\'')
+        .prefix(item.repeat(..).fold(
+            value((None, 0)),
+            |(first, count): (Option<(char, bool)>, usize), c| (first.or(Some(c)), count + 1),
+        ))
+        .skip(character(b'\''))
+        .try_map(|(c, count)| match c {
+            Some(c) if count == 1 => Ok(c),
+            Some(_) => Err("a single character"),
+            None => Err("a non-empty character literal"),
+        })
+}
+
+/// A raw string like Rust, `prefix` followed by any number of `#`s and a double quote, and ends
+/// with a double quote and the same number of `#`s, like `r#"a "quoted" string"#`.
+///
+/// If `strip_newline` is `true`, a newline just after the opening delimiter is removed.
+///
+/// # Panics
+/// if `prefix` contains non-ascii characters.
+pub fn raw_string<'a, E, I, C>(
+    prefix: &'static str,
+    strip_newline: bool,
+) -> impl Parser<I, Output = E> + 'a
+where
+    E: Extend<char> + Default + 'a,
+    I: Input<Ok = C> + ?Sized + 'a,
+    C: Character + Into<char> + 'a,
+{
+    characters(prefix)
+        .prefix(character(b'#').repeat(..).count())
+        .skip(character(b'"'))
+        .rewindable()
+        .spanned()
+        .then(move |hashes| {
+            delimited(
+                move || character(b'"').skip(character(b'#').times(hashes).discard()),
+                None,
+                strip_newline,
+            )
+        })
+        .try_map(terminated)
+        .expect("a raw string")
+}
+
+/// A string quoted by three `quote`s, like `"""` in TOML.
+///
+/// Up to two `quote`s just before the closing delimiter are parts of the content, so `""""a""""`
+/// is `"a"`, and runs of six or more `quote`s are rejected.
+///
+/// Escape sequences are allowed if `escapes` is [`Some`], and if `strip_newline` is `true`, a
+/// newline just after the opening delimiter is removed as TOML does.
+///
+/// # Panics
+/// if `quote` is not an ascii character.
+pub fn triple_quoted<'a, E, I, C>(
+    quote: u8,
+    escapes: Option<Escapes>,
+    strip_newline: bool,
+) -> impl Parser<I, Output = E> + 'a
+where
+    E: Extend<char> + Default + 'a,
+    I: Input<Ok = C> + ?Sized + 'a,
+    C: Character + Into<char> + 'a,
+{
+    let quotes = move || character(quote).times(3).discard();
+    let closing = move || quotes().skip(character(quote).fail());
+    let item = character(quote)
+        .times(6)
+        .discard()
+        .fail()
+        .expect("at most two quotes before the closing delimiter")
+        .prefix(content_char(escapes));
+
+    quotes()
+        .rewindable()
+        .spanned()
+        .prefix(delimited_by(closing, item, strip_newline))
+        .try_map(terminated)
+        .expect("a triple-quoted string")
+}
+
+/// A raw string like C++, `prefix` followed by a double quote, a delimiter of at most 16
+/// characters and a left parenthesis, and ends with a right parenthesis, the same delimiter and a
+/// double quote, like `R"x(a "quoted" string)x"`.
+///
+/// If `strip_newline` is `true`, a newline just after the opening delimiter is removed.
+///
+/// # Panics
+/// if `prefix` contains non-ascii characters.
+pub fn cpp_raw_string<'a, E, I, C>(
+    prefix: &'static str,
+    strip_newline: bool,
+) -> impl Parser<I, Output = E> + 'a
+where
+    E: Extend<char> + Default + 'a,
+    I: Input<Ok = C> + ?Sized + 'a,
+    C: Character + Into<char> + 'a,
+{
+    let delimiter = is(|c: &C| {
+        let c: char = c.clone().into();
+        c.is_ascii_graphic() && !matches!(c, '(' | ')' | '\\')
+    })
+    .repeat(..=16)
+    .fold(
+        value(([0u8; 16], 0)),
+        |(mut delimiter, len): ([u8; 16], usize), c| {
+            delimiter[len] = Into::<char>::into(c) as u8;
+            (delimiter, len + 1)
+        },
+    );
+
+    characters(prefix)
+        .prefix(character(b'"'))
+        .prefix(delimiter)
+        .skip(character(b'('))
+        .rewindable()
+        .spanned()
+        .then(move |(delimiter, len)| {
+            delimited(
+                move || {
+                    character(b')')
+                        .prefix(
+                            any()
+                                .times(len)
+                                .enumerate()
+                                .fold(value(true), move |eq, (i, c): (usize, C)| {
+                                    eq && c.eq_byte(delimiter[i])
+                                })
+                                .try_map(|eq| if eq { Ok(()) } else { Err("a delimiter") }),
+                        )
+                        .skip(character(b'"'))
+                },
+                None,
+                strip_newline,
+            )
+        })
+        .try_map(terminated)
+        .expect("a raw string")
+}
+
+/// A long bracket like Lua, starts with two left brackets with any number of `=`s between them,
+/// and ends with two right brackets with the same number of `=`s, like `[==[a ]] string]==]`.
+///
+/// If `strip_newline` is `true`, a newline just after the opening delimiter is removed as Lua
+/// does.
+pub fn long_bracket<'a, E, I, C>(strip_newline: bool) -> impl Parser<I, Output = E> + 'a
+where
+    E: Extend<char> + Default + 'a,
+    I: Input<Ok = C> + ?Sized + 'a,
+    C: Character + Into<char> + 'a,
+{
+    character(b'[')
+        .prefix(character(b'=').repeat(..).count())
+        .skip(character(b'['))
+        .rewindable()
+        .spanned()
+        .then(move |level| {
+            delimited(
+                move || {
+                    character(b']')
+                        .skip(character(b'=').times(level).discard())
+                        .skip(character(b']'))
+                },
+                None,
+                strip_newline,
+            )
+        })
+        .try_map(terminated)
+        .expect("a long string")
+}
+
+/// Parses the content of a string until `closing`, returns it and `true` if the string is
+/// terminated by `closing` rather than the end of input.
+fn delimited<'a, E, F, P, I, C>(
+    closing: F,
+    escapes: Option<Escapes>,
+    strip_newline: bool,
+) -> impl Parser<I, Output = (E, bool)> + 'a
+where
+    E: Extend<char> + Default + 'a,
+    F: Fn() -> P + 'a,
+    P: Parser<I> + 'a,
+    I: Input<Ok = C> + ?Sized + 'a,
+    C: Character + Into<char> + 'a,
+{
+    delimited_by(closing, content_char(escapes), strip_newline)
+}
+
+/// Like [`delimited`], but the characters are parsed by `item`.
+fn delimited_by<'a, E, F, P, Q, I, C>(
+    closing: F,
+    item: Q,
+    strip_newline: bool,
+) -> impl Parser<I, Output = (E, bool)> + 'a
+where
+    E: Extend<char> + Default + 'a,
+    F: Fn() -> P + 'a,
+    P: Parser<I> + 'a,
+    Q: Parser<I, Output = char> + 'a,
+    I: Input<Ok = C> + ?Sized + 'a,
+    C: Character + 'a,
+{
+    let newline = if strip_newline {
+        newline().opt().discard().left()
+    } else {
+        value(()).right()
+    };
+
+    newline.prefix(until_closing(item, closing))
+}
+
+/// Parses a character or an escape sequence if `escapes` is [`Some`].
+fn content_char<'a, I, C>(escapes: Option<Escapes>) -> impl Parser<I, Output = char> + 'a
+where
+    I: Input<Ok = C> + ?Sized + 'a,
+    C: Character + Into<char> + 'a,
+{
+    match escapes {
+        Some(escapes) => escape(escapes).or(any().map(Into::into)).left(),
+        None => any().map(Into::into).right(),
+    }
+}
+
+/// Collects outputs of `item` until `closing`, returns them and `true` if `closing` is found
+/// rather than the end of input.
+fn until_closing<'a, T, E, Q, F, P, I>(
+    item: Q,
+    closing: F,
+) -> impl Parser<I, Output = (E, bool)> + 'a
+where
+    E: Extend<T> + Default + 'a,
+    Q: Parser<I, Output = T> + 'a,
+    F: Fn() -> P + 'a,
+    P: Parser<I> + 'a,
+    I: Input + ?Sized + 'a,
+{
+    let end = move || {
+        closing()
+            .rewindable()
+            .spanned()
+            .map(|_| true)
+            .or(eof().map(|_| false))
+    };
+
+    item.until(end().peek()).collect().and(end())
+}
+
+fn terminated<E>((content, terminated): (E, bool)) -> Result<E, &'static str> {
+    if terminated {
+        Ok(content)
+    } else {
+        Err("the end of the string started here")
+    }
+}
+
+/// Languages defining rules of [`text_block`].
+#[cfg(feature = "alloc")]
+#[cfg_attr(feature = "nightly", doc(cfg(feature = "alloc")))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum TextBlock {
+    /// Text blocks of Java, quoted by `"""`.
+    ///
+    /// The minimal indentation of non-blank lines and the closing line is removed, and trailing
+    /// spaces are stripped. The string ends with a newline if the closing delimiter is on its own
+    /// line.
+    Java,
+    /// Multi-line strings of Swift, quoted by `"""`.
+    ///
+    /// The closing delimiter must be on its own line, and its indentation is removed from all the
+    /// lines. The newline before the closing delimiter is not included.
+    Swift,
+    /// Indented strings of Nix, quoted by `''`.
+    ///
+    /// The minimal indentation (only spaces) of non-blank lines is removed, and the first and the
+    /// last lines are dropped if they are blank. Note that interpolations are not supported.
+    Nix,
+    /// Here documents of shells, starts with `<<WORD` and ends with a line `WORD`.
+    ///
+    /// All the leading tabs of lines are removed if it starts with `<<-`. Escapes and line
+    /// continuations are disabled if the word is quoted like `<<'WORD'`, and expansions are never
+    /// performed.
+    ///
+    /// The word must be followed by a newline, so the rest of the command like `<<EOF > file` is
+    /// not supported.
+    Shell,
+}
+
+/// A multi-line string which removes leading indentation following `style`.
+///
+/// Newlines (CRLF, LF or CR) are normalized to LF, and a backslash followed by a newline
+/// continues the line except for Nix. Escape sequences are interpreted after removing
+/// indentation, following [`Escapes::java`] or [`Escapes::swift`] for Java or Swift.
+#[cfg(feature = "alloc")]
+#[cfg_attr(feature = "nightly", doc(cfg(feature = "alloc")))]
+pub fn text_block<'a, I, C>(style: TextBlock) -> impl Parser<I, Output = String> + 'a
+where
+    I: Input<Ok = C> + ?Sized + 'a,
+    C: Character + Into<char> + 'a,
+{
+    match style {
+        TextBlock::Java | TextBlock::Swift => {
+            let escapes = if style == TextBlock::Java {
+                Escapes::java()
+            } else {
+                Escapes::swift()
+            };
+            let piece = continuation()
+                .or(escape(escapes).map(Piece::Escaped))
+                .or(raw_piece());
+            let quotes = || characters("\"\"\"");
+            quotes()
+                .rewindable()
+                .spanned()
+                .prefix(until_closing(piece, quotes))
+                .try_map(move |lines: (Lines, bool)| terminated(lines)?.finish(style, false))
+                .left()
+        }
+        TextBlock::Nix => {
+            let escaped = characters("''").prefix(
+                character(b'$')
+                    .map(|_| Piece::Escaped('$'))
+                    .or(character(b'\'').map(|_| Piece::Quotes))
+                    .or(character(b'\\').prefix(any()).map(|c: C| {
+                        Piece::Escaped(match c.into() {
+                            'n' => '\n',
+                            'r' => '\r',
+                            't' => '\t',
+                            c => c,
+                        })
+                    })),
+            );
+            let quotes = || {
+                characters("''").skip(
+                    is(|c: &C| c.eq_byte(b'$') || c.eq_byte(b'\'') || c.eq_byte(b'\\')).fail(),
+                )
+            };
+            characters("''")
+                .rewindable()
+                .spanned()
+                .prefix(until_closing(escaped.or(raw_piece()), quotes))
+                .try_map(|lines: (Lines, bool)| terminated(lines)?.finish(TextBlock::Nix, false))
+                .left()
+                .right()
+        }
+        TextBlock::Shell => {
+            let word = is(|c: &C| {
+                let c: char = c.clone().into();
+                !c.is_whitespace() && !"<>|&;()'\"\\".contains(c)
+            })
+            .repeat(1..)
+            .map(Into::into)
+            .collect()
+            .map(|word| (word, false));
+            let quoted = |quote| {
+                character(quote)
+                    .prefix(
+                        is_not(move |c: &C| c.eq_byte(quote) || c.eq_byte(b'\n'))
+                            .repeat(1..)
+                            .map(Into::into)
+                            .collect(),
+                    )
+                    .skip(character(quote))
+                    .map(|word| (word, true))
+            };
+
+            characters("<<")
+                .prefix(character(b'-').opt().map(|dash| dash.is_some()))
+                .and(word.or(quoted(b'\'')).or(quoted(b'"')))
+                .rewindable()
+                .spanned()
+                .then(|(dash, (word, quoted)): (bool, (String, bool))| {
+                    let piece = if quoted {
+                        raw_piece().left()
+                    } else {
+                        let escaped = character(b'\\')
+                            .prefix(is(|c: &C| {
+                                c.eq_byte(b'\\') || c.eq_byte(b'$') || c.eq_byte(b'`')
+                            }))
+                            .rewindable()
+                            .spanned()
+                            .map(|c: C| Piece::Escaped(c.into()));
+                        continuation().or(escaped).or(raw_piece()).right()
+                    };
+                    let word: Vec<char> = word.chars().collect();
+                    let end = move || {
+                        let word = word.clone();
+                        newline()
+                            .skip(
+                                is(move |c: &C| dash && c.eq_byte(b'\t'))
+                                    .repeat(..)
+                                    .discard(),
+                            )
+                            .skip(
+                                any()
+                                    .times(word.len())
+                                    .enumerate()
+                                    .fold(value(true), move |eq, (i, c): (usize, C)| {
+                                        eq && c.into() == word[i]
+                                    })
+                                    .try_map(|eq| if eq { Ok(()) } else { Err("a delimiter") }),
+                            )
+                            .skip(newline().or(eof()).peek())
+                    };
+                    until_closing(piece, end).map(move |lines: (Lines, bool)| (lines, dash))
+                })
+                .try_map(|(lines, dash)| terminated(lines)?.finish(TextBlock::Shell, dash))
+                .right()
+                .right()
+        }
+    }
+}
+
+/// Parses a newline, CRLF, LF or CR.
+fn newline<'a, I, C>() -> impl Parser<I, Output = ()> + 'a
+where
+    I: Input<Ok = C> + ?Sized + 'a,
+    C: Character + 'a,
+{
+    character(b'\r')
+        .prefix(character(b'\n').opt())
+        .discard()
+        .or(character(b'\n').discard())
+}
+
+/// A piece of text blocks.
+#[cfg(feature = "alloc")]
+#[derive(Clone, Copy, Debug)]
+enum Piece {
+    Raw(char),
+    Escaped(char),
+    Quotes,
+    Newline,
+    Continuation,
+}
+
+#[cfg(feature = "alloc")]
+fn continuation<'a, I, C>() -> impl Parser<I, Output = Piece> + 'a
+where
+    I: Input<Ok = C> + ?Sized + 'a,
+    C: Character + 'a,
+{
+    character(b'\\')
+        .prefix(newline())
+        .rewindable()
+        .spanned()
+        .map(|_| Piece::Continuation)
+}
+
+#[cfg(feature = "alloc")]
+fn raw_piece<'a, I, C>() -> impl Parser<I, Output = Piece> + 'a
+where
+    I: Input<Ok = C> + ?Sized + 'a,
+    C: Character + Into<char> + 'a,
+{
+    newline()
+        .map(|_| Piece::Newline)
+        .or(any().map(|c: C| Piece::Raw(c.into())))
+}
+
+/// A line of text blocks.
+#[cfg(feature = "alloc")]
+#[derive(Clone, Debug)]
+struct Line {
+    text: String,
+    /// The length of leading spaces and tabs.
+    indent: usize,
+    /// The length of trailing spaces and tabs, not produced by escapes.
+    trailing: usize,
+    /// Whether the line only contains spaces and tabs.
+    blank: bool,
+    /// Whether the line ends with a newline rather than a line continuation.
+    newline: bool,
+}
+
+#[cfg(feature = "alloc")]
+impl Line {
+    fn new() -> Self {
+        Self {
+            text: String::new(),
+            indent: 0,
+            trailing: 0,
+            blank: true,
+            newline: false,
+        }
+    }
+
+    /// Returns the length of leading `c`s, not produced by escapes.
+    fn leading(&self, c: u8) -> usize {
+        self.text[..self.indent]
+            .bytes()
+            .take_while(|b| *b == c)
+            .count()
+    }
+}
+
+/// Lines of text blocks, collected from [`Piece`]s.
+#[cfg(feature = "alloc")]
+#[derive(Clone, Debug)]
+struct Lines(Vec<Line>);
+
+#[cfg(feature = "alloc")]
+impl Default for Lines {
+    #[inline]
+    fn default() -> Self {
+        Self(vec![Line::new()])
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl Extend<Piece> for Lines {
+    fn extend<T: IntoIterator<Item = Piece>>(&mut self, iter: T) {
+        for piece in iter {
+            let line = self.0.last_mut().unwrap();
+            match piece {
+                Piece::Raw(c) => {
+                    let space = c == ' ' || c == '\t';
+                    if space && line.indent == line.text.len() {
+                        line.indent += 1;
+                    }
+                    line.trailing = if space { line.trailing + 1 } else { 0 };
+                    line.blank &= space;
+                    line.text.push(c);
+                }
+                Piece::Escaped(c) => {
+                    line.trailing = 0;
+                    line.blank = false;
+                    line.text.push(c);
+                }
+                Piece::Quotes => {
+                    line.trailing = 0;
+                    line.blank = false;
+                    line.text.push_str("''");
+                }
+                Piece::Newline => {
+                    line.newline = true;
+                    self.0.push(Line::new());
+                }
+                Piece::Continuation => {
+                    // Spaces before a line continuation are not trailing.
+                    line.trailing = 0;
+                    self.0.push(Line::new());
+                }
+            }
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl Lines {
+    /// Removes indentation following `style`, and joins lines.
+    fn finish(self, style: TextBlock, strip_tabs: bool) -> Result<String, &'static str> {
+        let mut lines = self.0;
+        let first = lines.remove(0);
+        if !first.blank || (lines.is_empty() && style != TextBlock::Shell) {
+            if style == TextBlock::Nix {
+                lines.insert(0, first);
+            } else {
+                return Err("a newline after the opening delimiter");
+            }
+        }
+
+        let mut res = String::new();
+        match style {
+            TextBlock::Java => {
+                let last = lines.len() - 1;
+                let indent = lines
+                    .iter()
+                    .enumerate()
+                    .filter(|(i, line)| !line.blank || *i == last)
+                    .map(|(_, line)| line.indent)
+                    .min()
+                    .unwrap_or(0);
+                for line in lines {
+                    if !line.blank {
+                        res.push_str(&line.text[indent..line.text.len() - line.trailing]);
+                    }
+                    if line.newline {
+                        res.push('\n');
+                    }
+                }
+            }
+            TextBlock::Swift => {
+                let closing = lines.pop().unwrap();
+                if !closing.blank {
+                    return Err("a closing delimiter on its own line");
+                }
+                let last = lines.len().saturating_sub(1);
+                for (i, line) in lines.into_iter().enumerate() {
+                    // Escaped spaces and tabs are not indentation.
+                    if line.indent >= closing.indent && line.text.starts_with(&*closing.text) {
+                        res.push_str(&line.text[closing.indent..]);
+                    } else if !line.blank {
+                        return Err("an indentation same as the closing delimiter");
+                    }
+                    if line.newline && i != last {
+                        res.push('\n');
+                    }
+                }
+            }
+            TextBlock::Nix => {
+                if let Some(line) = lines.last_mut().filter(|line| line.blank) {
+                    line.text.clear();
+                    line.indent = 0;
+                }
+                let indent = lines
+                    .iter()
+                    .filter(|line| !line.blank)
+                    .map(|line| line.leading(b' '))
+                    .min()
+                    .unwrap_or(0);
+                for line in lines {
+                    res.push_str(&line.text[line.leading(b' ').min(indent)..]);
+                    if line.newline {
+                        res.push('\n');
+                    }
+                }
+            }
+            TextBlock::Shell => {
+                if let Some(line) = lines.last_mut() {
+                    line.newline = true;
+                }
+                for line in lines {
+                    let tabs = if strip_tabs { line.leading(b'\t') } else { 0 };
+                    res.push_str(&line.text[tabs..]);
+                    if line.newline {
+                        res.push('\n');
+                    }
+                }
+            }
+        }
+        Ok(res)
+    }
+}
+
+/// A part of interpolated strings, the output of [`interpolated`].
+#[cfg(feature = "alloc")]
+#[cfg_attr(feature = "nightly", doc(cfg(feature = "alloc")))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Segment<L, E> {
+    /// A literal part, with escape sequences unescaped.
+    Literal(L),
+    /// An interpolated expression.
+    Expr {
+        /// The expression.
+        expr: E,
+        /// A conversion like `r` of `{x!r}` in Python.
+        conversion: Option<char>,
+        /// A format specification like `>10` of `{x:>10}` in Python, which may contain nested
+        /// expressions like `{x:{width}}`.
+        spec: Option<Vec<Segment<L, E>>>,
+    },
+}
+
+/// Syntaxes of interpolations in [`interpolated`].
+#[cfg(feature = "alloc")]
+#[cfg_attr(feature = "nightly", doc(cfg(feature = "alloc")))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Interpolation {
+    /// Expressions in `${` and `}`, like JavaScript or Kotlin. A `$` not followed by `{` is a
+    /// literal character.
+    Dollar,
+    /// Expressions in `\(` and `)`, like Swift.
+    Backslash,
+    /// Expressions in braces, like Python f-strings. Literal braces are escaped by doubling like
+    /// `{{` and `}}`, and unpaired right braces are rejected.
+    ///
+    /// Expressions may be followed by a conversion `!s`, `!r` or `!a`, and a format specification
+    /// after a colon like `{x!r:>10}`. Specifications may contain nested expressions like
+    /// `{x:{width}}`, which may have their own conversions and specifications without nested
+    /// expressions.
+    Brace,
+}
+
+/// An interpolated string quoted by `quote`, returns literal parts and expressions parsed by
+/// `expr` in order.
+///
+/// `expr` must stop before the closing delimiter of the interpolation, and it may consume nested
+/// braces, parentheses or strings by itself, recursively with [`call!`] if needed. In the
+/// [`Brace`] style, `expr` must also stop before `!` of conversions and `:` of format
+/// specifications, and it is cloned to parse nested expressions in specifications. Note that
+/// self-documenting expressions like `{x=}` are not supported.
+///
+/// Empty literal parts are omitted, so `"${a}${b}"` yields just two expressions.
+///
+/// # Panics
+/// if `quote` is not an ascii character.
+///
+/// [`call!`]: somen::call
+/// [`Brace`]: Interpolation::Brace
+#[cfg(feature = "alloc")]
+#[cfg_attr(feature = "nightly", doc(cfg(feature = "alloc")))]
+pub fn interpolated<'a, L, X, P, I, C>(
+    quote: u8,
+    escapes: Escapes,
+    style: Interpolation,
+    expr: P,
+) -> impl Parser<I, Output = Vec<Segment<L, X>>> + 'a
+where
+    L: Extend<char> + Default + 'a,
+    X: 'a,
+    P: Parser<I, Output = X> + Clone + 'a,
+    I: Input<Ok = C> + ?Sized + 'a,
+    C: Character + Into<char> + 'a,
+{
+    let plain = |expr| {
+        Item::Segment(Segment::Expr {
+            expr,
+            conversion: None,
+            spec: None,
+        })
+    };
+    let interpolation = match style {
+        Interpolation::Dollar => characters("${")
+            .prefix(expr)
+            .skip(character(b'}'))
+            .map(plain)
+            .left()
+            .left(),
+        Interpolation::Backslash => characters("\\(")
+            .prefix(expr)
+            .skip(character(b')'))
+            .map(plain)
+            .right()
+            .left(),
+        Interpolation::Brace => format_field(quote, expr).right(),
+    };
+
+    let braces = move |brace| {
+        is(move |c: &C| style == Interpolation::Brace && c.eq_byte(brace))
+            .prefix(character(brace))
+            .rewindable()
+            .spanned()
+            .map(|c: C| Item::Char(c.into()))
+    };
+    let unpaired = is(move |c: &C| style == Interpolation::Brace && c.eq_byte(b'}'))
+        .try_map(|_| Err("a doubled `}`"));
+    let literal = is_not(move |c: &C| {
+        c.eq_byte(quote)
+            || c.eq_byte(b'\\')
+            || (style == Interpolation::Brace && (c.eq_byte(b'{') || c.eq_byte(b'}')))
+    })
+    .map(|c: C| Item::Char(c.into()))
+    .or(escape(escapes).map(Item::Char));
+
+    let item = braces(b'{')
+        .or(braces(b'}'))
+        .or(unpaired)
+        .or(interpolation)
+        .or(literal)
+        .expect("a character");
+
+    character(quote)
+        .prefix(until_closing(item, move || character(quote)))
+        .try_map(|res: (Segments<L, X>, bool)| terminated(res).map(|segments| segments.0))
+        .expect("an interpolated string")
+}
+
+/// Parses a replacement field of Python f-strings, an expression in braces with an optional
+/// conversion and an optional format specification.
+#[cfg(feature = "alloc")]
+fn format_field<'a, L, X, P, I, C>(quote: u8, expr: P) -> impl Parser<I, Output = Item<L, X>> + 'a
+where
+    L: Extend<char> + Default + 'a,
+    X: 'a,
+    P: Parser<I, Output = X> + Clone + 'a,
+    I: Input<Ok = C> + ?Sized + 'a,
+    C: Character + Into<char> + 'a,
+{
+    let spec_char = move || {
+        is_not(move |c: &C| c.eq_byte(b'{') || c.eq_byte(b'}') || c.eq_byte(quote))
+            .map(|c: C| Item::Char(c.into()))
+    };
+    let nested = replacement_field(expr.clone(), spec_char().repeat(..).collect());
+    replacement_field(expr, spec_char().or(nested).repeat(..).collect())
+}
+
+/// Parses an expression in braces with an optional conversion, and a format specification parsed
+/// by `spec`.
+#[cfg(feature = "alloc")]
+fn replacement_field<'a, L, X, P, S, I, C>(
+    expr: P,
+    spec: S,
+) -> impl Parser<I, Output = Item<L, X>> + 'a
+where
+    L: 'a,
+    X: 'a,
+    P: Parser<I, Output = X> + 'a,
+    S: Parser<I, Output = Segments<L, X>> + 'a,
+    I: Input<Ok = C> + ?Sized + 'a,
+    C: Character + Into<char> + 'a,
+{
+    let conversion = character(b'!').prefix(
+        is(|c: &C| c.eq_byte(b's') || c.eq_byte(b'r') || c.eq_byte(b'a'))
+            .map(Into::into)
+            .expect("a conversion `s`, `r` or `a`"),
+    );
+
+    character(b'{')
+        .prefix(expr)
+        .and(conversion.opt())
+        .and(character(b':').prefix(spec).opt())
+        .skip(character(b'}'))
+        .map(|((expr, conversion), spec)| {
+            Item::Segment(Segment::Expr {
+                expr,
+                conversion,
+                spec: spec.map(|spec| spec.0),
+            })
+        })
+}
+
+/// A character or a segment of interpolated strings.
+#[cfg(feature = "alloc")]
+enum Item<L, X> {
+    Char(char),
+    Segment(Segment<L, X>),
+}
+
+/// Segments of interpolated strings, collected from characters and segments.
+#[cfg(feature = "alloc")]
+struct Segments<L, X>(Vec<Segment<L, X>>);
+
+#[cfg(feature = "alloc")]
+impl<L, X> Default for Segments<L, X> {
+    #[inline]
+    fn default() -> Self {
+        Self(Vec::new())
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<L: Extend<char> + Default, X> Extend<Item<L, X>> for Segments<L, X> {
+    fn extend<T: IntoIterator<Item = Item<L, X>>>(&mut self, iter: T) {
+        for item in iter {
+            match (item, self.0.last_mut()) {
+                (Item::Char(c), Some(Segment::Literal(lit))) => lit.extend([c]),
+                (Item::Char(c), _) => {
+                    let mut lit = L::default();
+                    lit.extend([c]);
+                    self.0.push(Segment::Literal(lit));
+                }
+                (Item::Segment(segment), _) => self.0.push(segment),
+            }
+        }
+    }
+}
+
+#[cfg(all(test, feature = "alloc"))]
+mod tests {
+    use super::*;
+    use alloc::string::String;
+    use somen::stream;
+
+    macro_rules! parse {
+        ($parser:expr, $input:expr) => {
+            futures_executor::block_on(async {
+                let mut stream = stream::from_iter($input.chars())
+                    .positioned::<usize>()
+                    .buffered_rewind();
+                $parser.complete().parse(&mut stream).await.ok()
+            })
+        };
+    }
+
+    #[test]
+    fn octal_escapes() {
+        for (escapes, input, expected) in [
+            (Escapes::java(), r#""\0""#, "\0"),
+            (Escapes::java(), r#""\101""#, "A"),
+            (Escapes::java(), r#""\377""#, "\u{FF}"),
+            (Escapes::java(), r#""\477""#, "'7"),
+            (Escapes::java(), r#""\08""#, "\08"),
+            (Escapes::c(), r#""\377""#, "\u{FF}"),
+            (Escapes::c(), r#""\1010""#, "A0"),
+            (Escapes::python(), r#""\477""#, "\u{13F}"),
+        ] {
+            assert_eq!(
+                parse!(string::<String, _, _>(b"\"", escapes), input).as_deref(),
+                Some(expected),
+                "{input}"
+            );
+        }
+        assert_eq!(
+            parse!(string::<String, _, _>(b"\"", Escapes::c()), r#""\477""#),
+            None
+        );
+    }
+
+    #[test]
+    fn surrogate_escapes() {
+        assert_eq!(
+            parse!(
+                string::<String, _, _>(b"\"", Escapes::json()),
+                r#""\uD83D\uDE00""#
+            )
+            .as_deref(),
+            Some("\u{1F600}")
+        );
+        for (escapes, input) in [
+            (Escapes::json(), r#""\uD83D""#),
+            (Escapes::json(), r#""\uDE00""#),
+            (Escapes::c(), r#""\uD83D\uDE00""#),
+            (Escapes::c(), r#""\uD83D""#),
+        ] {
+            assert_eq!(
+                parse!(string::<String, _, _>(b"\"", escapes), input),
+                None,
+                "{input}"
+            );
+        }
+        assert_eq!(
+            parse!(string::<String, _, _>(b"\"", Escapes::c()), r#""\u00E9""#).as_deref(),
+            Some("\u{E9}")
+        );
+    }
+
+    #[test]
+    fn triple_quoted_quotes() {
+        for (input, expected) in [
+            ("\"\"\"a\"\"\"", "a"),
+            ("\"\"\"\"a\"\"\"\"", "\"a\""),
+            ("\"\"\"a\"\"\"\"\"", "a\"\""),
+            ("\"\"\"a\"\"b\"\"\"", "a\"\"b"),
+            ("\"\"\"\"\"\"", ""),
+            ("\"\"\"\"\"\"\"", "\""),
+            ("\"\"\"\"\"a\"\"\"", "\"\"a"),
+        ] {
+            assert_eq!(
+                parse!(triple_quoted::<String, _, _>(b'"', None, true), input).as_deref(),
+                Some(expected),
+                "{input}"
+            );
+        }
+        for input in ["\"\"\"a\"\"\"\"\"\"", "\"\"\"a\"\"\"\"\"\"\""] {
+            assert_eq!(
+                parse!(triple_quoted::<String, _, _>(b'"', None, true), input),
+                None,
+                "{input}"
+            );
+        }
+    }
+
+    #[test]
+    fn text_block_java() {
+        for (input, expected) in [
+            ("\"\"\"\n    a\n    b\n    \"\"\"", "a\nb\n"),
+            ("\"\"\"\n    a  \n      b\"\"\"", "a\n  b"),
+            ("\"\"\"\n    a  \\\n    b\n    \"\"\"", "a  b\n"),
+            ("\"\"\"\n    a\\s\n  \"\"\"", "  a \n"),
+        ] {
+            assert_eq!(
+                parse!(text_block(TextBlock::Java), input).as_deref(),
+                Some(expected),
+                "{input}"
+            );
+        }
+        assert_eq!(parse!(text_block(TextBlock::Java), "\"\"\"a\"\"\""), None);
+    }
+
+    #[test]
+    fn text_block_swift() {
+        for (input, expected) in [
+            ("\"\"\"\n    a\n      b\n    \"\"\"", "a\n  b"),
+            ("\"\"\"\n    a \\\n    b\n    \"\"\"", "a b"),
+            ("\"\"\"\n    a\n\n    \"\"\"", "a\n"),
+        ] {
+            assert_eq!(
+                parse!(text_block(TextBlock::Swift), input).as_deref(),
+                Some(expected),
+                "{input}"
+            );
+        }
+        for input in [
+            "\"\"\"\n  a\n    \"\"\"",
+            "\"\"\"\n\\t a\n\t \"\"\"",
+            "\"\"\"\n    a\"\"\"",
+        ] {
+            assert_eq!(parse!(text_block(TextBlock::Swift), input), None, "{input}");
+        }
+    }
+
+    #[test]
+    fn text_block_nix() {
+        for (input, expected) in [
+            ("''\n  a\n    b\n''", "a\n  b\n"),
+            ("''\n  a\n\n  b\n  ''", "a\n\nb\n"),
+            ("''\n''\\ a\n  b\n''", " a\n  b\n"),
+            ("''\n  ''\\ a\n  b\n''", " a\nb\n"),
+        ] {
+            assert_eq!(
+                parse!(text_block(TextBlock::Nix), input).as_deref(),
+                Some(expected),
+                "{input}"
+            );
+        }
+    }
+
+    #[test]
+    fn text_block_shell() {
+        for (input, expected) in [
+            ("<<EOF\nhello\n  world\nEOF", "hello\n  world\n"),
+            ("<<-EOF\n\thello\n\tEOF", "hello\n"),
+            ("<<EOF\na \\\nb\nEOF", "a b\n"),
+            ("<<'EOF'\na \\\nb\nEOF", "a \\\nb\n"),
+        ] {
+            assert_eq!(
+                parse!(text_block(TextBlock::Shell), input).as_deref(),
+                Some(expected),
+                "{input}"
+            );
+        }
+        for input in ["<<EOF > file\nhello\nEOF", "<<EOF | cat\nhello\nEOF"] {
+            assert_eq!(parse!(text_block(TextBlock::Shell), input), None, "{input}");
+        }
+    }
+
+    #[test]
+    fn interpolated_python() {
+        type Segments = Vec<Segment<String, String>>;
+        let ident = || {
+            is(|c: &char| c.is_alphabetic())
+                .repeat(1..)
+                .collect::<String>()
+        };
+        let f_string = || {
+            interpolated::<String, _, _, _, _>(
+                b'"',
+                Escapes::python(),
+                Interpolation::Brace,
+                ident(),
+            )
+        };
+        let lit = |s: &str| Segment::Literal(String::from(s));
+        let expr = |x: &str, conversion, spec| Segment::Expr {
+            expr: String::from(x),
+            conversion,
+            spec,
+        };
+
+        let cases: [(&str, Segments); 7] = [
+            (
+                r#""a{x}b""#,
+                vec![lit("a"), expr("x", None, None), lit("b")],
+            ),
+            (r#""{{x}}""#, vec![lit("{x}")]),
+            (r#""{x!r}""#, vec![expr("x", Some('r'), None)]),
+            (
+                r#""{x:>10}""#,
+                vec![expr("x", None, Some(vec![lit(">10")]))],
+            ),
+            (
+                r#""{x!r:>10}""#,
+                vec![expr("x", Some('r'), Some(vec![lit(">10")]))],
+            ),
+            (
+                r#""{x:{width}}""#,
+                vec![expr("x", None, Some(vec![expr("width", None, None)]))],
+            ),
+            (
+                r#""{x:{w}.{p!s:d}f}""#,
+                vec![expr(
+                    "x",
+                    None,
+                    Some(vec![
+                        expr("w", None, None),
+                        lit("."),
+                        expr("p", Some('s'), Some(vec![lit("d")])),
+                        lit("f"),
+                    ]),
+                )],
+            ),
+        ];
+        for (input, expected) in cases {
+            assert_eq!(parse!(f_string(), input), Some(expected), "{input}");
+        }
+        for input in [r#""{x!z}""#, r#""{x:{w:{y}}}""#, r#""{x:>10""#, r#""}""#] {
+            assert_eq!(parse!(f_string(), input), None, "{input}");
+        }
+
+        let template = interpolated::<String, _, _, _, _>(
+            b'"',
+            Escapes::json(),
+            Interpolation::Dollar,
+            ident(),
+        );
+        assert_eq!(
+            parse!(template, r#""${x}!r""#),
+            Some(vec![expr("x", None, None), lit("!r")])
+        );
+    }
+}